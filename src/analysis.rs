@@ -0,0 +1,394 @@
+use crate::rules::{Rule, RuleError, RuleType};
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use std::ops::RangeInclusive;
+
+/// 匹配方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MatchKind {
+    /// 按字面文本匹配
+    Literal,
+    /// 按正则表达式匹配
+    Regex,
+}
+
+/// 描述要统计的匹配模式
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchSpec {
+    /// 要匹配的文本或正则
+    pub pattern: String,
+    /// 匹配方式
+    pub kind: MatchKind,
+    /// 区分大小写
+    pub case_sensitive: bool,
+}
+
+impl MatchSpec {
+    fn to_regex(&self) -> Result<Regex, RuleError> {
+        let pattern = match self.kind {
+            MatchKind::Literal => regex::escape(&self.pattern),
+            MatchKind::Regex => self.pattern.clone(),
+        };
+        RegexBuilder::new(&pattern)
+            .case_insensitive(!self.case_sensitive)
+            .build()
+            .map_err(|e| RuleError::InvalidPattern {
+                pattern: self.pattern.clone(),
+                reason: e.to_string(),
+            })
+    }
+}
+
+/// 统计模式在每个文件名中出现的次数（不重叠），不修改文件名。
+/// 用于在执行前提示 "将有多少文件受影响"
+pub fn count_matches(names: &[String], rule: &MatchSpec) -> Result<Vec<usize>, RuleError> {
+    if rule.pattern.is_empty() {
+        return Ok(vec![0; names.len()]);
+    }
+    let re = rule.to_regex()?;
+    Ok(names
+        .iter()
+        .map(|name| re.find_iter(name).count())
+        .collect())
+}
+
+/// 两个字符串之间的 Levenshtein 编辑距离（按字符计算）
+fn levenshtein(a: &[char], b: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+/// 找出批次中相似度超过 threshold 的文件名对，返回 (下标, 下标, 相似度)。
+/// 相似度为 1 - 编辑距离 / 较长名称的字符数，比较前统一转为小写。
+/// 长度差已经使相似度不可能达到 threshold 的组合会被直接跳过
+pub fn find_similar(names: &[String], threshold: f64) -> Vec<(usize, usize, f64)> {
+    let chars: Vec<Vec<char>> = names
+        .iter()
+        .map(|name| name.to_lowercase().chars().collect())
+        .collect();
+
+    let mut pairs = Vec::new();
+    for i in 0..chars.len() {
+        for j in i + 1..chars.len() {
+            let (a, b) = (&chars[i], &chars[j]);
+            let longest = a.len().max(b.len());
+            if longest == 0 {
+                pairs.push((i, j, 1.0));
+                continue;
+            }
+            // 编辑距离至少为长度差，由此得到相似度的上界
+            let best_possible = 1.0 - a.len().abs_diff(b.len()) as f64 / longest as f64;
+            if best_possible <= threshold {
+                continue;
+            }
+            let similarity = 1.0 - levenshtein(a, b) as f64 / longest as f64;
+            if similarity > threshold {
+                pairs.push((i, j, similarity));
+            }
+        }
+    }
+    pairs
+}
+
+/// missing_numbers 最多返回的缺失值个数
+pub const MAX_MISSING: u64 = 100_000;
+
+/// 从每个文件名中提取编号（pattern 有捕获组时取第一个捕获组，否则取整个匹配），
+/// 返回从最小编号到最大编号之间的空缺，每段空缺为一个闭区间，按从小到大排列。
+/// 没有匹配或无法解析的文件名会被跳过；跨度再大也只占用与文件数成正比的内存
+pub fn missing_ranges(
+    names: &[String],
+    pattern: &str,
+) -> Result<Vec<RangeInclusive<i64>>, RuleError> {
+    let re = Regex::new(pattern).map_err(|e| RuleError::InvalidPattern {
+        pattern: pattern.to_string(),
+        reason: e.to_string(),
+    })?;
+    let mut numbers: Vec<i64> = names
+        .iter()
+        .filter_map(|name| {
+            let caps = re.captures(name)?;
+            let m = caps.get(1).or_else(|| caps.get(0))?;
+            m.as_str().parse().ok()
+        })
+        .collect();
+    numbers.sort_unstable();
+    numbers.dedup();
+
+    Ok(numbers
+        .windows(2)
+        .filter(|pair| pair[0] + 1 < pair[1])
+        .map(|pair| pair[0] + 1..=pair[1] - 1)
+        .collect())
+}
+
+/// 与 missing_ranges 相同，但返回每个缺少的值，按从小到大排列。
+/// 用于在重新编号前发现序列中的空缺，如 1、2、4 缺少 3。
+/// 缺少的值超过 MAX_MISSING 个时（如误把日期 "20230105" 当作编号）返回 TooManyMissing，
+/// 而不是分配巨大的列表，此时可以改用 missing_ranges
+pub fn missing_numbers(names: &[String], pattern: &str) -> Result<Vec<i64>, RuleError> {
+    let ranges = missing_ranges(names, pattern)?;
+    let count = ranges.iter().try_fold(0u64, |count, range| {
+        let len = range.end().abs_diff(*range.start()).saturating_add(1);
+        Some(count.saturating_add(len)).filter(|&c| c <= MAX_MISSING)
+    });
+    if count.is_none() {
+        return Err(RuleError::TooManyMissing(MAX_MISSING));
+    }
+    Ok(ranges.into_iter().flatten().collect())
+}
+
+/// 规则链中一条规则的影响
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuleImpact {
+    /// 规则类型
+    pub rule_type: RuleType,
+    /// 被这条规则改变的文件名数量
+    pub changed: usize,
+}
+
+/// 逐条运行规则链，统计每条规则实际改变了多少个文件名，
+/// 便于从很长的规则链中找出不起作用的规则。每条规则的输入是前面规则的输出
+pub fn rule_impact(inputs: &[String], rules: &[Rule]) -> Result<Vec<RuleImpact>, RuleError> {
+    let mut current = inputs.to_vec();
+    let mut impacts = Vec::with_capacity(rules.len());
+    for rule in rules {
+        let next = rule.apply(&current)?;
+        let changed = current.iter().zip(&next).filter(|(a, b)| a != b).count();
+        impacts.push(RuleImpact {
+            rule_type: rule.rule_type(),
+            changed,
+        });
+        current = next;
+    }
+    Ok(impacts)
+}
+
+/// SplitMix64 伪随机数生成器，相同的种子总是得到相同的序列
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// 从批次中抽取约 fraction 比例的文件用于预览，返回按升序排列的下标，
+/// 避免在几十万个文件上逐行预览。数量为 fraction * 总数四舍五入，fraction 会被限制在 [0, 1]；
+/// 相同的输入数量、比例和种子总是选出相同的下标
+pub fn sample_inputs(inputs: &[String], fraction: f64, seed: u64) -> Vec<usize> {
+    let fraction = if fraction.is_nan() {
+        0.0
+    } else {
+        fraction.clamp(0.0, 1.0)
+    };
+    let count = (inputs.len() as f64 * fraction).round() as usize;
+
+    // 部分 Fisher-Yates 洗牌：只需要打乱前 count 个位置
+    let mut indices: Vec<usize> = (0..inputs.len()).collect();
+    let mut rng = SplitMix64(seed);
+    for i in 0..count {
+        let j = i + (rng.next() % (indices.len() - i) as u64) as usize;
+        indices.swap(i, j);
+    }
+    indices.truncate(count);
+    indices.sort_unstable();
+    indices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    // 测试样例：字面/正则 + 区分/不区分大小写
+    #[rstest]
+    #[case("a", MatchKind::Literal, true, &[3, 0, 1])]
+    #[case("j", MatchKind::Literal, false, &[0, 1, 0])]
+    #[case(".", MatchKind::Literal, true, &[1, 1, 1])]
+    #[case(r"\d+", MatchKind::Regex, true, &[1, 0, 2])]
+    #[case("^dsc", MatchKind::Regex, false, &[0, 1, 0])]
+    #[case("", MatchKind::Literal, true, &[0, 0, 0])]
+    fn test_count_matches(
+        #[case] pattern: &str,
+        #[case] kind: MatchKind,
+        #[case] case_sensitive: bool,
+        #[case] expected: &[usize],
+    ) {
+        let spec = MatchSpec {
+            pattern: pattern.to_string(),
+            kind,
+            case_sensitive,
+        };
+        let input = names(&["banana 01.txt", "DSC.JPG", "a1 b22.md"]);
+
+        let result = count_matches(&input, &spec).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_count_matches_invalid_regex() {
+        let spec = MatchSpec {
+            pattern: "(unclosed".to_string(),
+            kind: MatchKind::Regex,
+            case_sensitive: true,
+        };
+
+        let result = count_matches(&names(&["a"]), &spec);
+        assert!(matches!(result, Err(RuleError::InvalidPattern { .. })));
+    }
+
+    // 测试样例：编辑距离
+    #[rstest]
+    #[case("kitten", "sitting", 3)]
+    #[case("", "abc", 3)]
+    #[case("same", "same", 0)]
+    fn test_levenshtein(#[case] a: &str, #[case] b: &str, #[case] expected: usize) {
+        let a: Vec<char> = a.chars().collect();
+        let b: Vec<char> = b.chars().collect();
+        assert_eq!(levenshtein(&a, &b), expected);
+    }
+
+    #[test]
+    fn test_find_similar() {
+        let input = names(&[
+            "report final",
+            "report_final",
+            "holiday photo",
+            "Report Final",
+        ]);
+
+        let pairs = find_similar(&input, 0.8);
+        let indices: Vec<(usize, usize)> = pairs.iter().map(|&(i, j, _)| (i, j)).collect();
+        assert_eq!(indices, vec![(0, 1), (0, 3), (1, 3)]);
+
+        let (_, _, similarity) = pairs[0];
+        assert!((similarity - (1.0 - 1.0 / 12.0)).abs() < 1e-9);
+        assert_eq!(pairs[1].2, 1.0);
+    }
+
+    #[test]
+    fn test_find_similar_skips_different_lengths() {
+        let input = names(&["a", "a very long name"]);
+        assert!(find_similar(&input, 0.5).is_empty());
+    }
+
+    // 测试样例：提取编号并找出空缺
+    #[rstest]
+    #[case(&["ep1.mp4", "ep2.mp4", "ep4.mp4"], r"ep(\d+)", &[3])]
+    #[case(&["07.jpg", "03.jpg", "notes.txt", "04.jpg"], r"\d+", &[5, 6])]
+    #[case(&["a1", "a2", "a3"], r"a(\d+)", &[])]
+    #[case(&["a-2", "a3", "a1"], r"a(-?\d+)", &[-1, 0, 2])]
+    #[case(&["none.txt"], r"\d+", &[])]
+    fn test_missing_numbers(
+        #[case] input: &[&str],
+        #[case] pattern: &str,
+        #[case] expected: &[i64],
+    ) {
+        let result = missing_numbers(&names(input), pattern).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_missing_numbers_wide_span() {
+        let input = names(&["a1.txt", "a9999999999.txt", "IMG_20230105.jpg", "a3.txt"]);
+        let result = missing_numbers(&input, r"\d+");
+        assert!(matches!(
+            result,
+            Err(RuleError::TooManyMissing(MAX_MISSING))
+        ));
+
+        let result = missing_ranges(&input, r"\d+").unwrap();
+        assert_eq!(result, [2..=2, 4..=20230104, 20230106..=9999999998]);
+    }
+
+    #[test]
+    fn test_missing_ranges() {
+        let input = names(&["a-2", "a3", "a1"]);
+        let result = missing_ranges(&input, r"a(-?\d+)").unwrap();
+        assert_eq!(result, [-1..=0, 2..=2]);
+    }
+
+    #[test]
+    fn test_missing_numbers_invalid_pattern() {
+        let result = missing_numbers(&names(&["a1"]), "(unclosed");
+        assert!(matches!(result, Err(RuleError::InvalidPattern { .. })));
+    }
+
+    #[test]
+    fn test_rule_impact() {
+        use crate::rules::{CaseKind, CaseRule, RemoveRule, StripQueryRule};
+
+        let rules = vec![
+            Rule::Remove(RemoveRule {
+                text: "DSC_".to_string(),
+                case_sensitive: true,
+                ignore_extension: true,
+                ..Default::default()
+            }),
+            Rule::StripQuery(StripQueryRule {
+                ignore_extension: true,
+            }),
+            Rule::Case(CaseRule {
+                case: CaseKind::Lower,
+                ignore_extension: true,
+                ..Default::default()
+            }),
+        ];
+        let input = names(&["DSC_Beach.jpg", "DSC_sun.jpg", "Notes.txt"]);
+
+        let impacts = rule_impact(&input, &rules).unwrap();
+        let summary: Vec<(RuleType, usize)> =
+            impacts.iter().map(|i| (i.rule_type, i.changed)).collect();
+        assert_eq!(
+            summary,
+            vec![
+                (RuleType::Remove, 2),
+                (RuleType::StripQuery, 0),
+                (RuleType::Case, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sample_inputs() {
+        let input: Vec<String> = (0..1000).map(|i| format!("file{}.txt", i)).collect();
+
+        let sample = sample_inputs(&input, 0.05, 42);
+        assert_eq!(sample.len(), 50);
+        assert!(sample.windows(2).all(|w| w[0] < w[1]));
+        assert!(sample.iter().all(|&i| i < input.len()));
+        // 相同的种子得到相同的结果，不同的种子（几乎总是）不同
+        assert_eq!(sample_inputs(&input, 0.05, 42), sample);
+        assert_ne!(sample_inputs(&input, 0.05, 7), sample);
+    }
+
+    #[rstest]
+    #[case(0.0, 0)]
+    #[case(1.0, 10)]
+    #[case(1.5, 10)]
+    #[case(-0.5, 0)]
+    #[case(0.25, 3)]
+    #[case(f64::NAN, 0)]
+    fn test_sample_inputs_size(#[case] fraction: f64, #[case] expected: usize) {
+        let input = names(&["a", "b", "c", "d", "e", "f", "g", "h", "i", "j"]);
+        assert_eq!(sample_inputs(&input, fraction, 1).len(), expected);
+    }
+}
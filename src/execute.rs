@@ -0,0 +1,264 @@
+use crate::plan::{PlanOptions, RenamePlan, RenameStatus, plan_renames};
+use crate::rules::Rule;
+use anyhow::{Result, bail};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 判断 to 是否与 from 指向同一个文件（如大小写不敏感文件系统上只改大小写）
+fn is_same_file(from: &Path, to: &Path) -> bool {
+    match (fs::canonicalize(from), fs::canonicalize(to)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// 执行计划的方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ApplyMode {
+    /// 把原文件重命名为新名称
+    #[default]
+    Rename,
+    /// 以新名称复制一份，原文件保持不变。不支持目录
+    Copy,
+    /// 以新名称创建指向原文件的硬链接。不支持目录，且新旧路径必须在同一文件系统上
+    Hardlink,
+    /// 以新名称创建指向原文件绝对路径的符号链接。
+    /// Windows 上需要开发者模式或管理员权限
+    Symlink,
+}
+
+/// 在 link 处创建指向 target 的符号链接
+#[cfg(unix)]
+fn symlink(target: &Path, link: &Path, _is_dir: bool) -> Result<()> {
+    std::os::unix::fs::symlink(target, link)?;
+    Ok(())
+}
+
+/// 在 link 处创建指向 target 的符号链接，Windows 区分文件和目录的符号链接
+#[cfg(windows)]
+fn symlink(target: &Path, link: &Path, is_dir: bool) -> Result<()> {
+    if is_dir {
+        std::os::windows::fs::symlink_dir(target, link)?;
+    } else {
+        std::os::windows::fs::symlink_file(target, link)?;
+    }
+    Ok(())
+}
+
+#[cfg(not(any(unix, windows)))]
+fn symlink(_target: &Path, link: &Path, _is_dir: bool) -> Result<()> {
+    bail!("当前平台不支持符号链接: {}", link.display());
+}
+
+/// 按顺序执行计划中状态为 Renamed 的条目，冲突和未改变的条目会被跳过。
+/// 目标已存在（且重命名时不是同一个文件）时停止并返回错误，不会覆盖已有文件
+pub fn execute_plan(plan: &RenamePlan, mode: ApplyMode) -> Result<()> {
+    for item in &plan.items {
+        if item.status != RenameStatus::Renamed {
+            continue;
+        }
+        // 复制或创建链接时即使目标与原文件是同一个文件（如只改大小写）也不能继续，否则会覆盖原文件
+        let same_file = mode == ApplyMode::Rename && is_same_file(&item.from, &item.to);
+        if fs::symlink_metadata(&item.to).is_ok() && !same_file {
+            bail!("目标已存在: {}", item.to.display());
+        }
+        match mode {
+            ApplyMode::Rename => fs::rename(&item.from, &item.to)?,
+            ApplyMode::Copy => {
+                if item.is_dir {
+                    bail!("无法复制目录: {}", item.from.display());
+                }
+                fs::copy(&item.from, &item.to)?;
+            }
+            ApplyMode::Hardlink => {
+                if item.is_dir {
+                    bail!("无法为目录创建硬链接: {}", item.from.display());
+                }
+                fs::hard_link(&item.from, &item.to)?;
+            }
+            ApplyMode::Symlink => {
+                // 使用绝对路径，使链接不依赖当前工作目录
+                symlink(&fs::canonicalize(&item.from)?, &item.to, item.is_dir)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 递归收集目录下的所有文件和子目录（不包含 root 自身，不跟随符号链接）
+fn collect_tree(dir: &Path, paths: &mut Vec<PathBuf>) -> Result<()> {
+    let mut entries: Vec<_> = fs::read_dir(dir)?.collect::<Result<_, _>>()?;
+    entries.sort_by_key(|entry| entry.file_name());
+    for entry in entries {
+        let path = entry.path();
+        paths.push(path.clone());
+        if entry.file_type()?.is_dir() {
+            collect_tree(&path, paths)?;
+        }
+    }
+    Ok(())
+}
+
+/// 对 root 下的所有文件和目录应用规则并执行重命名。
+/// 计划按路径深度从深到浅执行，先重命名子项再重命名父目录，
+/// 这样父目录改名时不会让尚未处理的子路径失效。返回实际执行的计划
+pub fn execute_tree(root: &Path, rules: &[Rule], options: &PlanOptions) -> Result<RenamePlan> {
+    let mut paths = Vec::new();
+    collect_tree(root, &mut paths)?;
+
+    let mut plan = plan_renames(&paths, rules, options)?;
+    plan.items
+        .sort_by_key(|item| std::cmp::Reverse(item.from.components().count()));
+    execute_plan(&plan, ApplyMode::Rename)?;
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plan::RenameItem;
+    use crate::rules::{CaseKind, CaseRule};
+
+    fn lower() -> Rule {
+        Rule::Case(CaseRule {
+            case: CaseKind::Lower,
+            ignore_extension: false,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_execute_tree_renames_nested_dirs_and_files() {
+        let root = tempfile::tempdir().unwrap();
+        let nested = root.path().join("Photos").join("Trip");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(nested.join("IMG_01.JPG"), "").unwrap();
+        fs::write(root.path().join("Photos").join("Cover.PNG"), "").unwrap();
+
+        let plan = execute_tree(root.path(), &[lower()], &PlanOptions::default()).unwrap();
+
+        assert!(root.path().join("photos/trip/img_01.jpg").is_file());
+        assert!(root.path().join("photos/cover.png").is_file());
+        assert!(!root.path().join("Photos").exists());
+        // 深度大的条目排在前面
+        let depths: Vec<usize> = plan
+            .items
+            .iter()
+            .map(|item| item.from.components().count())
+            .collect();
+        assert!(depths.windows(2).all(|w| w[0] >= w[1]));
+    }
+
+    #[test]
+    fn test_execute_plan_refuses_to_overwrite() {
+        let root = tempfile::tempdir().unwrap();
+        let from = root.path().join("a.txt");
+        let to = root.path().join("b.txt");
+        fs::write(&from, "a").unwrap();
+        fs::write(&to, "b").unwrap();
+
+        let plan = RenamePlan {
+            items: vec![RenameItem {
+                from: from.clone(),
+                to: to.clone(),
+                is_dir: false,
+                status: RenameStatus::Renamed,
+            }],
+        };
+
+        assert!(execute_plan(&plan, ApplyMode::Rename).is_err());
+        assert_eq!(fs::read_to_string(&to).unwrap(), "b");
+        assert!(from.exists());
+    }
+
+    fn item(from: PathBuf, to: PathBuf, is_dir: bool) -> RenameItem {
+        RenameItem {
+            from,
+            to,
+            is_dir,
+            status: RenameStatus::Renamed,
+        }
+    }
+
+    #[test]
+    fn test_execute_plan_copy_keeps_originals() {
+        let root = tempfile::tempdir().unwrap();
+        let from = root.path().join("a.txt");
+        let to = root.path().join("b.txt");
+        fs::write(&from, "hello").unwrap();
+
+        let plan = RenamePlan {
+            items: vec![item(from.clone(), to.clone(), false)],
+        };
+
+        execute_plan(&plan, ApplyMode::Copy).unwrap();
+        assert_eq!(fs::read_to_string(&from).unwrap(), "hello");
+        assert_eq!(fs::read_to_string(&to).unwrap(), "hello");
+        // 目标已存在时不会覆盖
+        assert!(execute_plan(&plan, ApplyMode::Copy).is_err());
+    }
+
+    #[test]
+    fn test_execute_plan_copy_rejects_dirs() {
+        let root = tempfile::tempdir().unwrap();
+        let from = root.path().join("dir");
+        fs::create_dir(&from).unwrap();
+
+        let plan = RenamePlan {
+            items: vec![item(from.clone(), root.path().join("copy"), true)],
+        };
+
+        assert!(execute_plan(&plan, ApplyMode::Copy).is_err());
+        assert!(!root.path().join("copy").exists());
+    }
+
+    #[test]
+    fn test_execute_plan_hardlink() {
+        let root = tempfile::tempdir().unwrap();
+        let from = root.path().join("a.txt");
+        let to = root.path().join("b.txt");
+        fs::write(&from, "hello").unwrap();
+
+        let plan = RenamePlan {
+            items: vec![item(from.clone(), to.clone(), false)],
+        };
+
+        execute_plan(&plan, ApplyMode::Hardlink).unwrap();
+        assert!(from.exists());
+        // 硬链接共享内容：通过原路径修改后新路径也能看到
+        fs::write(&from, "changed").unwrap();
+        assert_eq!(fs::read_to_string(&to).unwrap(), "changed");
+
+        let dir = root.path().join("dir");
+        fs::create_dir(&dir).unwrap();
+        let plan = RenamePlan {
+            items: vec![item(dir, root.path().join("dir_link"), true)],
+        };
+        assert!(execute_plan(&plan, ApplyMode::Hardlink).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_execute_plan_symlink() {
+        let root = tempfile::tempdir().unwrap();
+        let from = root.path().join("a.txt");
+        let to = root.path().join("b.txt");
+        fs::write(&from, "hello").unwrap();
+
+        let plan = RenamePlan {
+            items: vec![item(from.clone(), to.clone(), false)],
+        };
+
+        execute_plan(&plan, ApplyMode::Symlink).unwrap();
+        assert!(from.is_file());
+        assert!(fs::symlink_metadata(&to).unwrap().file_type().is_symlink());
+        assert_eq!(
+            fs::read_link(&to).unwrap(),
+            fs::canonicalize(&from).unwrap()
+        );
+        assert_eq!(fs::read_to_string(&to).unwrap(), "hello");
+        // 目标已存在时不会覆盖
+        assert!(execute_plan(&plan, ApplyMode::Symlink).is_err());
+    }
+}
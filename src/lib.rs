@@ -1 +1,5 @@
+pub mod analysis;
+pub mod execute;
+pub mod plan;
+pub mod preview;
 pub mod rules;
@@ -0,0 +1,388 @@
+use crate::rules::{Rule, RuleError, apply_rules};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// 目标文件系统所在的操作系统，决定文件名的比较方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetOs {
+    Windows,
+    Unix,
+}
+
+impl TargetOs {
+    /// 当前编译目标所在的操作系统
+    pub fn current() -> Self {
+        if cfg!(windows) {
+            TargetOs::Windows
+        } else {
+            TargetOs::Unix
+        }
+    }
+
+    /// 将文件名规范化为目标系统上实际落盘的形式，用于碰撞检测。
+    /// Windows 会静默丢弃文件名末尾的点和空格，因此 "a." 与 "a " 实际上都是 "a"
+    pub fn normalize_name(&self, name: &str) -> String {
+        match self {
+            TargetOs::Windows => name.trim_end_matches(['.', ' ']).to_string(),
+            TargetOs::Unix => name.to_string(),
+        }
+    }
+}
+
+impl Default for TargetOs {
+    fn default() -> Self {
+        TargetOs::current()
+    }
+}
+
+/// 规则作用的对象类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Target {
+    /// 只作用于文件
+    Files,
+    /// 只作用于目录
+    Dirs,
+    /// 文件和目录都作用
+    #[default]
+    Both,
+}
+
+impl Target {
+    pub fn matches(&self, is_dir: bool) -> bool {
+        match self {
+            Target::Files => !is_dir,
+            Target::Dirs => is_dir,
+            Target::Both => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PlanOptions {
+    /// 目标操作系统
+    pub target_os: TargetOs,
+    /// 规则作用的对象类型
+    pub applies_to: Target,
+    /// 按不区分大小写比较目标，适用于 Windows、macOS 默认的大小写不敏感文件系统。
+    /// 不确定时可用 detect_case_insensitive 探测目标目录
+    pub case_insensitive_collisions: bool,
+}
+
+impl PlanOptions {
+    /// 按目标系统的常见默认值创建选项：Windows 下按不区分大小写检测冲突
+    pub fn for_os(target_os: TargetOs) -> Self {
+        PlanOptions {
+            target_os,
+            case_insensitive_collisions: target_os == TargetOs::Windows,
+            ..Default::default()
+        }
+    }
+}
+
+/// 探测目录所在的文件系统是否大小写不敏感：
+/// 创建一个小写的探测文件，再检查其大写形式是否存在
+pub fn detect_case_insensitive(dir: &Path) -> io::Result<bool> {
+    let probe = dir.join(format!(".renamer-case-probe-{}", std::process::id()));
+    fs::write(&probe, "")?;
+    let upper = dir.join(format!(".RENAMER-CASE-PROBE-{}", std::process::id()));
+    let result = upper.exists();
+    fs::remove_file(&probe)?;
+    Ok(result)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RenameStatus {
+    /// 规则没有改变文件名
+    Unchanged,
+    /// 文件将被重命名
+    Renamed,
+    /// 目标与批次中的其他文件冲突
+    Collision,
+}
+
+#[derive(Debug, Clone)]
+pub struct RenameItem {
+    /// 原路径
+    pub from: PathBuf,
+    /// 目标路径
+    pub to: PathBuf,
+    /// 原路径是否为目录
+    pub is_dir: bool,
+    /// 重命名状态
+    pub status: RenameStatus,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RenamePlan {
+    pub items: Vec<RenameItem>,
+}
+
+/// 对每个路径的文件名应用规则链，生成重命名计划并标记目标冲突
+pub fn plan_renames(
+    paths: &[PathBuf],
+    rules: &[Rule],
+    options: &PlanOptions,
+) -> Result<RenamePlan, RuleError> {
+    let is_dirs: Vec<bool> = paths.iter().map(|p| p.is_dir()).collect();
+    let names: Vec<String> = paths
+        .iter()
+        .map(|p| {
+            p.file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        })
+        .collect();
+
+    // 只把作用范围内的文件名交给规则链，其余保持原名
+    let in_scope: Vec<usize> = (0..paths.len())
+        .filter(|&i| options.applies_to.matches(is_dirs[i]))
+        .collect();
+    let scoped_names: Vec<String> = in_scope.iter().map(|&i| names[i].clone()).collect();
+    let mut new_names = names.clone();
+    for (&index, new_name) in in_scope.iter().zip(apply_rules(&scoped_names, rules)?) {
+        new_names[index] = new_name;
+    }
+
+    let mut items: Vec<RenameItem> = paths
+        .iter()
+        .zip(names.iter().zip(new_names))
+        .zip(is_dirs)
+        .map(|((path, (old_name, new_name)), is_dir)| {
+            let status = if *old_name == new_name {
+                RenameStatus::Unchanged
+            } else {
+                RenameStatus::Renamed
+            };
+            RenameItem {
+                from: path.clone(),
+                to: path.with_file_name(new_name),
+                is_dir,
+                status,
+            }
+        })
+        .collect();
+
+    // 按 "父目录 + 规范化后的文件名" 分组，同组超过一个即为冲突
+    let mut groups: HashMap<(PathBuf, String), Vec<usize>> = HashMap::new();
+    for (index, item) in items.iter().enumerate() {
+        let parent = item.to.parent().map(PathBuf::from).unwrap_or_default();
+        let name = item
+            .to
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let mut name = options.target_os.normalize_name(&name);
+        if options.case_insensitive_collisions {
+            name = name.to_lowercase();
+        }
+        let key = (parent, name);
+        groups.entry(key).or_default().push(index);
+    }
+    for indices in groups.values().filter(|indices| indices.len() > 1) {
+        for &index in indices {
+            items[index].status = RenameStatus::Collision;
+        }
+    }
+
+    Ok(RenamePlan { items })
+}
+
+/// 生成撤销计划：交换每个已重命名条目的原路径与目标路径，只在内存中预览撤销效果。
+/// 冲突和未改变的条目不会被执行，因此不会出现在撤销计划中
+pub fn inverse_plan(plan: &RenamePlan) -> RenamePlan {
+    let items = plan
+        .items
+        .iter()
+        .filter(|item| item.status == RenameStatus::Renamed)
+        .map(|item| RenameItem {
+            from: item.to.clone(),
+            to: item.from.clone(),
+            is_dir: item.is_dir,
+            status: RenameStatus::Renamed,
+        })
+        .collect();
+    RenamePlan { items }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{RemovePosition, RemoveRule};
+    use rstest::rstest;
+
+    fn paths(names: &[&str]) -> Vec<PathBuf> {
+        names.iter().map(|n| PathBuf::from("dir").join(n)).collect()
+    }
+
+    // 测试样例：仅末尾点/空格不同的文件名在 Windows 上冲突，在 Unix 上不冲突
+    #[rstest]
+    #[case(&["a.", "a"], TargetOs::Windows, true)]
+    #[case(&["a ", "a"], TargetOs::Windows, true)]
+    #[case(&["a. .", "a"], TargetOs::Windows, true)]
+    #[case(&["a.", "a"], TargetOs::Unix, false)]
+    #[case(&["a ", "a"], TargetOs::Unix, false)]
+    #[case(&["a", "b"], TargetOs::Windows, false)]
+    fn test_trailing_dots_and_spaces_collision(
+        #[case] names: &[&str],
+        #[case] target_os: TargetOs,
+        #[case] expected_collision: bool,
+    ) {
+        let options = PlanOptions {
+            target_os,
+            ..Default::default()
+        };
+        let plan = plan_renames(&paths(names), &[], &options).unwrap();
+
+        let collided = plan
+            .items
+            .iter()
+            .all(|item| item.status == RenameStatus::Collision);
+        assert_eq!(collided, expected_collision);
+    }
+
+    #[test]
+    fn test_rule_output_collision() {
+        let rule = Rule::Remove(RemoveRule {
+            text: "x".to_string(),
+            remove_position: RemovePosition::All,
+            case_sensitive: true,
+            ignore_extension: true,
+            ..Default::default()
+        });
+        let options = PlanOptions {
+            target_os: TargetOs::Windows,
+            ..Default::default()
+        };
+        let plan = plan_renames(&paths(&["ax.txt", "a.txt", "b.txt"]), &[rule], &options).unwrap();
+
+        let statuses: Vec<_> = plan.items.iter().map(|item| item.status.clone()).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                RenameStatus::Collision,
+                RenameStatus::Collision,
+                RenameStatus::Unchanged
+            ]
+        );
+        assert_eq!(plan.items[0].to, PathBuf::from("dir").join("a.txt"));
+    }
+
+    // 测试样例：只对目录应用规则，文件保持原名
+    #[rstest]
+    #[case(Target::Dirs, "photos_x", "notes_x.txt", "photos", "notes_x.txt")]
+    #[case(Target::Files, "photos_x", "notes_x.txt", "photos_x", "notes.txt")]
+    #[case(Target::Both, "photos_x", "notes_x.txt", "photos", "notes.txt")]
+    fn test_applies_to(
+        #[case] applies_to: Target,
+        #[case] dir_name: &str,
+        #[case] file_name: &str,
+        #[case] expected_dir: &str,
+        #[case] expected_file: &str,
+    ) {
+        let root = tempfile::tempdir().unwrap();
+        let dir = root.path().join(dir_name);
+        let file = root.path().join(file_name);
+        std::fs::create_dir(&dir).unwrap();
+        std::fs::write(&file, "").unwrap();
+
+        let rule = Rule::Remove(RemoveRule {
+            text: "_x".to_string(),
+            remove_position: RemovePosition::All,
+            case_sensitive: true,
+            ignore_extension: true,
+            ..Default::default()
+        });
+        let options = PlanOptions {
+            applies_to,
+            ..Default::default()
+        };
+        let plan = plan_renames(&[dir, file], &[rule], &options).unwrap();
+
+        assert!(plan.items[0].is_dir);
+        assert!(!plan.items[1].is_dir);
+        assert_eq!(plan.items[0].to, root.path().join(expected_dir));
+        assert_eq!(plan.items[1].to, root.path().join(expected_file));
+    }
+
+    /// 在内存中模拟执行计划：只有状态为 Renamed 的条目会被改名
+    fn apply_in_memory(current: &[PathBuf], plan: &RenamePlan) -> Vec<PathBuf> {
+        current
+            .iter()
+            .map(|path| {
+                plan.items
+                    .iter()
+                    .find(|item| item.status == RenameStatus::Renamed && item.from == *path)
+                    .map(|item| item.to.clone())
+                    .unwrap_or_else(|| path.clone())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_inverse_plan_round_trip() {
+        let rule = Rule::Remove(RemoveRule {
+            text: "_x".to_string(),
+            remove_position: RemovePosition::All,
+            case_sensitive: true,
+            ignore_extension: true,
+            ..Default::default()
+        });
+        let options = PlanOptions {
+            target_os: TargetOs::Unix,
+            ..Default::default()
+        };
+        // "a_x" 与 "a" 冲突，"b" 不变，只有 "c_x" 会被重命名
+        let original = paths(&["a_x.txt", "a.txt", "b.txt", "c_x.txt"]);
+        let plan = plan_renames(&original, &[rule], &options).unwrap();
+
+        let inverse = inverse_plan(&plan);
+        assert_eq!(inverse.items.len(), 1);
+        assert_eq!(inverse.items[0].from, PathBuf::from("dir").join("c.txt"));
+        assert_eq!(inverse.items[0].to, PathBuf::from("dir").join("c_x.txt"));
+
+        let renamed = apply_in_memory(&original, &plan);
+        assert_eq!(renamed, paths(&["a_x.txt", "a.txt", "b.txt", "c.txt"]));
+        assert_eq!(apply_in_memory(&renamed, &inverse), original);
+    }
+
+    // 测试样例：仅大小写不同的目标只在不区分大小写模式下冲突
+    #[rstest]
+    #[case(true, true)]
+    #[case(false, false)]
+    fn test_case_insensitive_collisions(
+        #[case] case_insensitive_collisions: bool,
+        #[case] expected_collision: bool,
+    ) {
+        let options = PlanOptions {
+            target_os: TargetOs::Unix,
+            case_insensitive_collisions,
+            ..Default::default()
+        };
+        let plan = plan_renames(&paths(&["File.txt", "file.txt"]), &[], &options).unwrap();
+
+        let collided = plan
+            .items
+            .iter()
+            .all(|item| item.status == RenameStatus::Collision);
+        assert_eq!(collided, expected_collision);
+    }
+
+    #[test]
+    fn test_for_os_defaults() {
+        assert!(PlanOptions::for_os(TargetOs::Windows).case_insensitive_collisions);
+        assert!(!PlanOptions::for_os(TargetOs::Unix).case_insensitive_collisions);
+    }
+
+    #[test]
+    fn test_detect_case_insensitive_cleans_up() {
+        let dir = tempfile::tempdir().unwrap();
+        let detected = detect_case_insensitive(dir.path()).unwrap();
+
+        if cfg!(target_os = "linux") {
+            assert!(!detected);
+        }
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+}
@@ -0,0 +1,221 @@
+use crate::plan::{RenamePlan, RenameStatus};
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+/// 源路径列的最大宽度，超出的行不再对齐，避免一个超长路径撑开整张表
+const MAX_FROM_WIDTH: usize = 60;
+
+fn status_marker(status: &RenameStatus) -> &'static str {
+    match status {
+        RenameStatus::Renamed => " ",
+        RenameStatus::Unchanged => "=",
+        RenameStatus::Collision => "!",
+    }
+}
+
+/// 把重命名计划逐行写入 writer，每行形如 `! from -> to`：
+/// 行首标记 `=` 表示不变，`!` 表示冲突。
+/// 先扫描一遍计算源路径列宽，再逐行输出，不会在内存中拼出完整的预览文本
+pub fn write_preview<W: Write>(plan: &RenamePlan, w: &mut W) -> io::Result<()> {
+    let width = plan
+        .items
+        .iter()
+        .map(|item| item.from.display().to_string().chars().count())
+        .max()
+        .unwrap_or(0)
+        .min(MAX_FROM_WIDTH);
+
+    for item in &plan.items {
+        writeln!(
+            w,
+            "{} {:<width$} -> {}",
+            status_marker(&item.status),
+            item.from.display().to_string(),
+            item.to.display(),
+            width = width
+        )?;
+    }
+    Ok(())
+}
+
+/// 生成完整的预览文本，适合小批量；大批量请直接使用 write_preview
+pub fn format_preview(plan: &RenamePlan) -> String {
+    let mut buffer = Vec::new();
+    write_preview(plan, &mut buffer).expect("写入 Vec 不会失败");
+    String::from_utf8_lossy(&buffer).into_owned()
+}
+
+/// 一次重命名中被替换的片段：去掉新旧文件名的公共前缀和后缀后剩下的部分
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Transformation {
+    /// 原文件名中被移除的片段
+    pub removed: String,
+    /// 新文件名中新增的片段
+    pub added: String,
+}
+
+/// 重命名计划的统计信息
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Summary {
+    /// 将被重命名的文件数
+    pub changed: usize,
+    /// 文件名不变的文件数
+    pub unchanged: usize,
+    /// 目标冲突的文件数
+    pub collided: usize,
+    /// 所有重命名新增的字符数
+    pub chars_added: usize,
+    /// 所有重命名移除的字符数
+    pub chars_removed: usize,
+    /// 出现次数最多的变化及其次数，次数相同时取先出现的
+    pub most_common: Option<(Transformation, usize)>,
+}
+
+fn transformation(from: &str, to: &str) -> Transformation {
+    let from: Vec<char> = from.chars().collect();
+    let to: Vec<char> = to.chars().collect();
+    let prefix = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+    let suffix = from[prefix..]
+        .iter()
+        .rev()
+        .zip(to[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    Transformation {
+        removed: from[prefix..from.len() - suffix].iter().collect(),
+        added: to[prefix..to.len() - suffix].iter().collect(),
+    }
+}
+
+/// 统计重命名计划中各状态的文件数，以及重命名文件的字符变化
+pub fn summarize(plan: &RenamePlan) -> Summary {
+    let mut summary = Summary::default();
+    let mut counts: HashMap<Transformation, (usize, usize)> = HashMap::new();
+    for (index, item) in plan.items.iter().enumerate() {
+        match item.status {
+            RenameStatus::Unchanged => summary.unchanged += 1,
+            RenameStatus::Collision => summary.collided += 1,
+            RenameStatus::Renamed => {
+                summary.changed += 1;
+                let name = |p: &std::path::Path| {
+                    p.file_name()
+                        .map(|s| s.to_string_lossy().into_owned())
+                        .unwrap_or_default()
+                };
+                let change = transformation(&name(&item.from), &name(&item.to));
+                summary.chars_added += change.added.chars().count();
+                summary.chars_removed += change.removed.chars().count();
+                // 记录首次出现的位置，用于次数相同时保持稳定的结果
+                counts.entry(change).or_insert((0, index)).0 += 1;
+            }
+        }
+    }
+    summary.most_common = counts
+        .into_iter()
+        .max_by(|(_, (a, first_a)), (_, (b, first_b))| a.cmp(b).then(first_b.cmp(first_a)))
+        .map(|(change, (count, _))| (change, count));
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plan::RenameItem;
+    use std::path::PathBuf;
+
+    fn item(from: &str, to: &str, status: RenameStatus) -> RenameItem {
+        RenameItem {
+            from: PathBuf::from(from),
+            to: PathBuf::from(to),
+            is_dir: false,
+            status,
+        }
+    }
+
+    #[test]
+    fn test_write_preview() {
+        let plan = RenamePlan {
+            items: vec![
+                item("a.txt", "A.txt", RenameStatus::Renamed),
+                item("long_name.txt", "long_name.txt", RenameStatus::Unchanged),
+                item("b.txt", "x.txt", RenameStatus::Collision),
+            ],
+        };
+
+        let mut buffer = Vec::new();
+        write_preview(&plan, &mut buffer).unwrap();
+        let expected = concat!(
+            "  a.txt         -> A.txt\n",
+            "= long_name.txt -> long_name.txt\n",
+            "! b.txt         -> x.txt\n",
+        );
+        assert_eq!(String::from_utf8(buffer).unwrap(), expected);
+        assert_eq!(format_preview(&plan), expected);
+    }
+
+    #[test]
+    fn test_write_preview_caps_width() {
+        let long = "x".repeat(MAX_FROM_WIDTH + 10);
+        let plan = RenamePlan {
+            items: vec![
+                item("a", "b", RenameStatus::Renamed),
+                item(&long, "c", RenameStatus::Renamed),
+            ],
+        };
+
+        let preview = format_preview(&plan);
+        let lines: Vec<&str> = preview.lines().collect();
+        assert_eq!(
+            lines[0],
+            format!("  a{} -> b", " ".repeat(MAX_FROM_WIDTH - 1))
+        );
+        assert_eq!(lines[1], format!("  {} -> c", long));
+    }
+
+    #[test]
+    fn test_summarize() {
+        let plan = RenamePlan {
+            items: vec![
+                item("dir/a_1.txt", "dir/a-1.txt", RenameStatus::Renamed),
+                item("dir/b_2.txt", "dir/b-2.txt", RenameStatus::Renamed),
+                item(
+                    "dir/draft.txt",
+                    "dir/final draft.txt",
+                    RenameStatus::Renamed,
+                ),
+                item("dir/c.txt", "dir/c.txt", RenameStatus::Unchanged),
+                item("dir/x.txt", "dir/y.txt", RenameStatus::Collision),
+                item("dir/z.txt", "dir/y.txt", RenameStatus::Collision),
+            ],
+        };
+
+        let summary = summarize(&plan);
+        assert_eq!(summary.changed, 3);
+        assert_eq!(summary.unchanged, 1);
+        assert_eq!(summary.collided, 2);
+        assert_eq!(summary.chars_added, 8);
+        assert_eq!(summary.chars_removed, 2);
+        assert_eq!(
+            summary.most_common,
+            Some((
+                Transformation {
+                    removed: "_".to_string(),
+                    added: "-".to_string(),
+                },
+                2
+            ))
+        );
+    }
+
+    #[test]
+    fn test_summarize_empty() {
+        assert_eq!(summarize(&RenamePlan::default()), Summary::default());
+    }
+
+    #[test]
+    fn test_write_preview_empty() {
+        let mut buffer = Vec::new();
+        write_preview(&RenamePlan::default(), &mut buffer).unwrap();
+        assert!(buffer.is_empty());
+    }
+}
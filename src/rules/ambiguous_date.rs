@@ -0,0 +1,121 @@
+use super::common::split_name_numeric;
+use chrono::NaiveDate;
+use regex::{Captures, Regex};
+use serde::{Deserialize, Serialize};
+
+/// 日期中日和月的先后顺序
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DateOrder {
+    /// 月-日-年
+    MDY,
+    /// 日-月-年
+    DMY,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AmbiguousDateRule {
+    /// 日和月都不超过 12、无法判断顺序时采用的假设
+    pub assume: DateOrder,
+}
+
+/// 识别 "13-05-2023"、"5.13.2023" 这类日月顺序不确定的日期，并统一改写为 ISO 格式 (YYYY-MM-DD)。
+///
+/// 判断规则：
+/// - 第一个数大于 12：它不可能是月份，按日-月-年处理
+/// - 第二个数大于 12：按月-日-年处理
+/// - 两个数都不超过 12：真正有歧义，按 assume 处理
+/// - 两个数都大于 12，或组合出的日期不存在（如 31-02）：不是日期，保持原样
+///
+/// 分隔符可以是 "-"、"." 或 "_"，但同一个日期中必须一致；两侧紧邻数字时不处理。
+/// 没有扩展名时，结尾的年份（如 "report 05.03.2023" 中的 "2023"）不会被当成扩展名
+pub fn ambiguous_date(old_text: &str, rule: AmbiguousDateRule) -> String {
+    let (stem, extension) = split_name_numeric(old_text, true);
+
+    let re = Regex::new(r"(\d{1,2})([-._])(\d{1,2})([-._])(\d{4})").expect("日期正则是合法的");
+    let fixed = re.replace_all(&stem, |caps: &Captures| {
+        let original = caps[0].to_string();
+        let whole = caps.get(0).expect("总是有整体匹配");
+        let digit_before = stem[..whole.start()]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_ascii_digit());
+        let digit_after = stem[whole.end()..]
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_digit());
+        if digit_before || digit_after || caps[2] != caps[4] {
+            return original;
+        }
+
+        let first: u32 = caps[1].parse().unwrap_or(0);
+        let second: u32 = caps[3].parse().unwrap_or(0);
+        let year: i32 = caps[5].parse().unwrap_or(0);
+        let order = match (first > 12, second > 12) {
+            (true, false) => DateOrder::DMY,
+            (false, true) => DateOrder::MDY,
+            (false, false) => rule.assume,
+            (true, true) => return original,
+        };
+        let (month, day) = match order {
+            DateOrder::MDY => (first, second),
+            DateOrder::DMY => (second, first),
+        };
+        match NaiveDate::from_ymd_opt(year, month, day) {
+            Some(date) => date.format("%Y-%m-%d").to_string(),
+            None => original,
+        }
+    });
+
+    format!("{}{}", fixed, extension)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：不依赖假设即可确定顺序的日期
+    #[rstest]
+    #[case("report 13-05-2023.pdf", DateOrder::MDY, "report 2023-05-13.pdf")]
+    #[case("report 05_13_2023.pdf", DateOrder::DMY, "report 2023-05-13.pdf")]
+    #[case("scan 1.31.2024.png", DateOrder::DMY, "scan 2024-01-31.png")]
+    #[case("report 25.03.2023", DateOrder::MDY, "report 2023-03-25")]
+    fn test_ambiguous_date_unambiguous(
+        #[case] input: &str,
+        #[case] assume: DateOrder,
+        #[case] expected: &str,
+    ) {
+        let result = ambiguous_date(input, AmbiguousDateRule { assume });
+        assert_eq!(result, expected);
+    }
+
+    // 测试样例：有歧义的日期按 assume 处理
+    #[rstest]
+    #[case("memo 03-04-2023.txt", DateOrder::MDY, "memo 2023-03-04.txt")]
+    #[case("memo 03-04-2023.txt", DateOrder::DMY, "memo 2023-04-03.txt")]
+    #[case("report 05.03.2023", DateOrder::DMY, "report 2023-03-05")]
+    fn test_ambiguous_date_assume(
+        #[case] input: &str,
+        #[case] assume: DateOrder,
+        #[case] expected: &str,
+    ) {
+        let result = ambiguous_date(input, AmbiguousDateRule { assume });
+        assert_eq!(result, expected);
+    }
+
+    // 测试样例：不是日期的内容保持原样
+    #[rstest]
+    #[case("codes 13-14-2023.txt")]
+    #[case("codes 31-02-2023.txt")]
+    #[case("codes 03-04.2023.txt")]
+    #[case("id 103-04-2023.txt")]
+    #[case("plain name.txt")]
+    fn test_ambiguous_date_untouched(#[case] input: &str) {
+        let rule = AmbiguousDateRule {
+            assume: DateOrder::DMY,
+        };
+
+        let result = ambiguous_date(input, rule);
+        assert_eq!(result, input);
+    }
+}
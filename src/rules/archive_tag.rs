@@ -0,0 +1,113 @@
+use super::common::{InsertPosition, insert_text};
+use super::context::RuleContext;
+use super::error::RuleError;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// 压缩包常见的扩展名，小写
+const ARCHIVE_EXTENSIONS: [&str; 8] = ["zip", "rar", "7z", "tar", "gz", "bz2", "xz", "tgz"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveTagRule {
+    /// 插入的位置
+    pub position: InsertPosition,
+    /// 压缩包名与原文件名之间的分隔符
+    pub separator: String,
+}
+
+/// 去掉压缩包名中的扩展名和分卷标记，如 "photos.tar.gz" -> "photos"、
+/// "backup.part1.rar" -> "backup"、"data.7z.001" -> "data"
+fn archive_stem(archive_name: &str) -> &str {
+    let mut stem = archive_name;
+    while let Some((head, ext)) = stem.rsplit_once('.') {
+        let ext = ext.to_lowercase();
+        // 分卷编号如 ".001"，至少三位，避免把 "v1.2" 中的 "2" 当成分卷
+        let is_volume = (ext.len() >= 3 && ext.chars().all(|c| c.is_ascii_digit()))
+            || ext
+                .strip_prefix("part")
+                .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()));
+        if head.is_empty() || !(ARCHIVE_EXTENSIONS.contains(&ext.as_str()) || is_volume) {
+            break;
+        }
+        stem = head;
+    }
+    stem
+}
+
+/// 把文件来源的压缩包名（去掉扩展名）插入到文件名中，便于区分从不同压缩包解压出的文件。
+/// 上下文中没有压缩包名时保持原文件名
+pub fn archive_tag(
+    path: &Path,
+    rule: ArchiveTagRule,
+    context: &RuleContext,
+) -> Result<String, RuleError> {
+    let old_text = path
+        .file_name()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    let Some(archive_name) = context.archive_name.as_deref() else {
+        return Ok(old_text);
+    };
+    let stem = archive_stem(archive_name);
+    if stem.is_empty() {
+        return Ok(old_text);
+    }
+    Ok(insert_text(
+        &old_text,
+        stem,
+        rule.position,
+        &rule.separator,
+        true,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("photos_2023.zip", "photos_2023")]
+    #[case("photos.tar.gz", "photos")]
+    #[case("backup.part1.rar", "backup")]
+    #[case("data.7z.001", "data")]
+    #[case("v1.2.zip", "v1.2")]
+    #[case("no_extension", "no_extension")]
+    fn test_archive_stem(#[case] archive_name: &str, #[case] expected: &str) {
+        assert_eq!(archive_stem(archive_name), expected);
+    }
+
+    // 测试样例：提供上下文中的压缩包名，作为前缀或后缀
+    #[rstest]
+    #[case(InsertPosition::Prefix, "trip.zip", "trip_IMG_01.jpg")]
+    #[case(InsertPosition::Suffix, "trip.tar.gz", "IMG_01_trip.jpg")]
+    fn test_archive_tag(
+        #[case] position: InsertPosition,
+        #[case] archive_name: &str,
+        #[case] expected: &str,
+    ) {
+        let rule = ArchiveTagRule {
+            position,
+            separator: "_".to_string(),
+        };
+        let context = RuleContext {
+            archive_name: Some(archive_name.to_string()),
+            ..Default::default()
+        };
+
+        let result = archive_tag(Path::new("extracted/IMG_01.jpg"), rule, &context).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_archive_tag_without_label() {
+        let rule = ArchiveTagRule {
+            position: InsertPosition::Prefix,
+            separator: "_".to_string(),
+        };
+
+        let result = archive_tag(Path::new("IMG_01.jpg"), rule, &RuleContext::default()).unwrap();
+        assert_eq!(result, "IMG_01.jpg");
+    }
+}
@@ -0,0 +1,158 @@
+use super::Rule;
+use super::error::RuleError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// 按扩展名划分的文件类别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Category {
+    Image,
+    Audio,
+    Video,
+    Document,
+    Archive,
+}
+
+/// 默认的扩展名 -> 类别对照表，扩展名均为小写
+const CATEGORY_TABLE: [(Category, &[&str]); 5] = [
+    (
+        Category::Image,
+        &[
+            "jpg", "jpeg", "png", "gif", "bmp", "webp", "tif", "tiff", "heic", "svg", "raw",
+        ],
+    ),
+    (
+        Category::Audio,
+        &["mp3", "wav", "flac", "aac", "ogg", "m4a", "wma", "opus"],
+    ),
+    (
+        Category::Video,
+        &["mp4", "mkv", "avi", "mov", "wmv", "webm", "flv", "m4v"],
+    ),
+    (
+        Category::Document,
+        &[
+            "txt", "md", "pdf", "doc", "docx", "xls", "xlsx", "ppt", "pptx", "odt", "rtf", "csv",
+        ],
+    ),
+    (
+        Category::Archive,
+        &["zip", "rar", "7z", "tar", "gz", "bz2", "xz"],
+    ),
+];
+
+impl Category {
+    /// 根据扩展名（不区分大小写）判断文件的类别，未知扩展名返回 None
+    pub fn of(name: &str) -> Option<Category> {
+        let ext = Path::new(name)
+            .extension()?
+            .to_string_lossy()
+            .to_lowercase();
+        CATEGORY_TABLE
+            .iter()
+            .find(|(_, extensions)| extensions.contains(&ext.as_str()))
+            .map(|(category, _)| *category)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ByCategoryRule {
+    /// 每个类别使用的规则
+    pub categories: HashMap<Category, Box<Rule>>,
+    /// 不属于任何已配置类别的文件使用的规则，为 None 时保持原样
+    pub default: Option<Box<Rule>>,
+}
+
+/// 按文件类别分别应用不同的规则，便于用一份配置处理混杂的文件夹。
+/// 同一规则的文件作为一批处理，批量规则（如编号）只在各自的类别内生效
+pub fn by_category(names: &[String], rule: ByCategoryRule) -> Result<Vec<String>, RuleError> {
+    // 按选中的规则分组，记录每组文件在原列表中的下标
+    let mut groups: Vec<(&Rule, Vec<usize>)> = Vec::new();
+    for (index, name) in names.iter().enumerate() {
+        let selected = Category::of(name)
+            .and_then(|category| rule.categories.get(&category))
+            .or(rule.default.as_ref());
+        let Some(selected) = selected else {
+            continue;
+        };
+        match groups
+            .iter_mut()
+            .find(|(r, _)| std::ptr::eq(*r, selected.as_ref()))
+        {
+            Some((_, indices)) => indices.push(index),
+            None => groups.push((selected, vec![index])),
+        }
+    }
+
+    let mut results = names.to_vec();
+    for (selected, indices) in groups {
+        let batch: Vec<String> = indices.iter().map(|&i| names[i].clone()).collect();
+        for (index, renamed) in indices.into_iter().zip(selected.apply(&batch)?) {
+            results[index] = renamed;
+        }
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{CaseKind, CaseRule, InsertPosition, VariantSuffixRule};
+    use rstest::rstest;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn upper() -> Box<Rule> {
+        Box::new(Rule::Case(CaseRule {
+            case: CaseKind::Upper,
+            ignore_extension: true,
+            ..Default::default()
+        }))
+    }
+
+    fn prefix(text: &str) -> Box<Rule> {
+        Box::new(Rule::VariantSuffix(VariantSuffixRule {
+            suffix: text.to_string(),
+            position: InsertPosition::Prefix,
+        }))
+    }
+
+    #[rstest]
+    #[case("photo.jpg", Some(Category::Image))]
+    #[case("Song.FLAC", Some(Category::Audio))]
+    #[case("notes.txt", Some(Category::Document))]
+    #[case("backup.7z", Some(Category::Archive))]
+    #[case("Makefile", None)]
+    #[case("data.xyz", None)]
+    fn test_category_of(#[case] name: &str, #[case] expected: Option<Category>) {
+        assert_eq!(Category::of(name), expected);
+    }
+
+    #[test]
+    fn test_by_category() {
+        let rule = ByCategoryRule {
+            categories: HashMap::from([
+                (Category::Image, prefix("IMG_")),
+                (Category::Document, upper()),
+            ]),
+            default: None,
+        };
+
+        let result = by_category(&names(&["photo.jpg", "notes.txt", "song.mp3"]), rule).unwrap();
+        assert_eq!(result, names(&["IMG_photo.jpg", "NOTES.txt", "song.mp3"]));
+    }
+
+    #[test]
+    fn test_by_category_default() {
+        let rule = ByCategoryRule {
+            categories: HashMap::from([(Category::Image, prefix("IMG_"))]),
+            default: Some(upper()),
+        };
+
+        let result = by_category(&names(&["photo.jpg", "notes.txt", "Makefile"]), rule).unwrap();
+        assert_eq!(result, names(&["IMG_photo.jpg", "NOTES.txt", "MAKEFILE"]));
+    }
+}
@@ -0,0 +1,78 @@
+use super::common::split_name;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CaesarRule {
+    /// 字母移动的位数，可以为负数；13 即 ROT13
+    pub shift: i32,
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+}
+
+fn rotate(c: char, shift: i32) -> char {
+    let base = match c {
+        'a'..='z' => b'a',
+        'A'..='Z' => b'A',
+        _ => return c, // 非 ASCII 字母保持原样
+    };
+    let offset = (c as u8 - base) as i32;
+    (base + (offset + shift).rem_euclid(26) as u8) as char
+}
+
+/// 在 a-z 与 A-Z 内循环移动 ASCII 字母
+pub fn caesar(old_text: &str, rule: CaesarRule) -> String {
+    let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
+
+    let rotated: String = name_to_process
+        .chars()
+        .map(|c| rotate(c, rule.shift))
+        .collect();
+    format!("{}{}", rotated, extension_to_append)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：移动字母 + 在 z 处回绕
+    #[rstest]
+    #[case("Hello World.txt", 13, "Uryyb Jbeyq.txt")]
+    #[case("xyz XYZ.txt", 3, "abc ABC.txt")]
+    #[case("abc.txt", -1, "zab.txt")]
+    #[case("abc.txt", 27, "bcd.txt")]
+    #[case("中文 2023-01.txt", 5, "中文 2023-01.txt")]
+    fn test_caesar(#[case] input: &str, #[case] shift: i32, #[case] expected: &str) {
+        let rule = CaesarRule {
+            shift,
+            ignore_extension: true,
+        };
+
+        let result = caesar(input, rule);
+        assert_eq!(result, expected);
+    }
+
+    // 测试样例：ROT13 是自身的逆变换，其他位移可用相反数还原
+    #[rstest]
+    #[case("Secret Plan_v2.docx", 13, 13)]
+    #[case("Zebra-Quiz.md", 7, -7)]
+    fn test_caesar_round_trip(#[case] input: &str, #[case] shift: i32, #[case] back: i32) {
+        let forward = caesar(
+            input,
+            CaesarRule {
+                shift,
+                ignore_extension: false,
+            },
+        );
+        assert_ne!(forward, input);
+
+        let result = caesar(
+            &forward,
+            CaesarRule {
+                shift: back,
+                ignore_extension: false,
+            },
+        );
+        assert_eq!(result, input);
+    }
+}
@@ -0,0 +1,65 @@
+use super::common::split_name;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CamelSplitRule {
+    /// 插入到单词之间的分隔符
+    pub separator: char,
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+}
+
+/// 在驼峰命名的单词边界插入分隔符，如 "myFileName2" -> "my File Name 2"。
+/// 连续的大写字母视为一个单词（"HTTPServer" -> "HTTP Server"），
+/// 数字串之前以及数字串之后的大写字母之前也会插入分隔符
+pub fn camel_split(old_text: &str, rule: CamelSplitRule) -> String {
+    let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
+
+    let chars: Vec<char> = name_to_process.chars().collect();
+    let mut result = String::with_capacity(name_to_process.len());
+    for (i, &c) in chars.iter().enumerate() {
+        if i > 0 {
+            let prev = chars[i - 1];
+            let next = chars.get(i + 1).copied();
+            let boundary = (prev.is_lowercase() && c.is_uppercase())
+                // 大写串的最后一个字母属于后面的单词
+                || (prev.is_uppercase()
+                    && c.is_uppercase()
+                    && next.is_some_and(char::is_lowercase))
+                || (prev.is_alphabetic() && c.is_ascii_digit())
+                || (prev.is_ascii_digit() && c.is_uppercase());
+            if boundary {
+                result.push(rule.separator);
+            }
+        }
+        result.push(c);
+    }
+
+    format!("{}{}", result, extension_to_append)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：几种常见的标识符风格
+    #[rstest]
+    #[case("myFileName2.txt", ' ', "my File Name 2.txt")]
+    #[case("HTTPServer.log", ' ', "HTTP Server.log")]
+    #[case("parseJSONData.rs", '_', "parse_JSON_Data.rs")]
+    #[case("MyClass.java", ' ', "My Class.java")]
+    #[case("version10Final.doc", '-', "version-10-Final.doc")]
+    #[case("already split.txt", ' ', "already split.txt")]
+    #[case("snake_case_name.txt", ' ', "snake_case_name.txt")]
+    #[case("ALLCAPS.txt", ' ', "ALLCAPS.txt")]
+    fn test_camel_split(#[case] input: &str, #[case] separator: char, #[case] expected: &str) {
+        let rule = CamelSplitRule {
+            separator,
+            ignore_extension: true,
+        };
+
+        let result = camel_split(input, rule);
+        assert_eq!(result, expected);
+    }
+}
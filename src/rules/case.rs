@@ -0,0 +1,125 @@
+use super::common::split_name;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CaseKind {
+    /// 全部小写
+    #[default]
+    Lower,
+    /// 全部大写
+    Upper,
+    /// 每个单词首字母大写，其余小写
+    Title,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CaseRule {
+    /// 目标大小写形式
+    pub case: CaseKind,
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+    /// 转换为 Title 时保留已经全部大写、至少两个字符的单词（如 "NASA"）
+    #[serde(default)]
+    pub preserve_acronyms: bool,
+}
+
+fn is_acronym(word: &str) -> bool {
+    word.chars().count() >= 2
+        && word.chars().any(char::is_alphabetic)
+        && !word.chars().any(char::is_lowercase)
+}
+
+fn title_case(text: &str, preserve_acronyms: bool) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut word = String::new();
+    let flush = |word: &mut String, result: &mut String| {
+        if preserve_acronyms && is_acronym(word) {
+            result.push_str(word);
+        } else {
+            let mut chars = word.chars();
+            if let Some(first) = chars.next() {
+                result.extend(first.to_uppercase());
+                result.extend(chars.flat_map(char::to_lowercase));
+            }
+        }
+        word.clear();
+    };
+    for c in text.chars() {
+        // 字母和数字之外的字符（空格、下划线、连字符等）之后开始新单词
+        if c.is_alphanumeric() || c == '\'' {
+            word.push(c);
+        } else {
+            flush(&mut word, &mut result);
+            result.push(c);
+        }
+    }
+    flush(&mut word, &mut result);
+    result
+}
+
+pub fn case(old_text: &str, rule: CaseRule) -> String {
+    let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
+
+    let converted = match rule.case {
+        CaseKind::Lower => name_to_process.to_lowercase(),
+        CaseKind::Upper => name_to_process.to_uppercase(),
+        CaseKind::Title => title_case(&name_to_process, rule.preserve_acronyms),
+    };
+    format!("{}{}", converted, extension_to_append)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：三种大小写形式 + 忽略扩展名
+    #[rstest]
+    #[case("My File.TXT", CaseKind::Lower, true, "my file.TXT")]
+    #[case("My File.TXT", CaseKind::Lower, false, "my file.txt")]
+    #[case("My File.txt", CaseKind::Upper, true, "MY FILE.txt")]
+    #[case(
+        "the QUICK brown_fox-jumps.txt",
+        CaseKind::Title,
+        true,
+        "The Quick Brown_Fox-Jumps.txt"
+    )]
+    #[case("don't stop.mp3", CaseKind::Title, true, "Don't Stop.mp3")]
+    fn test_case(
+        #[case] input: &str,
+        #[case] kind: CaseKind,
+        #[case] ignore_extension: bool,
+        #[case] expected: &str,
+    ) {
+        let rule = CaseRule {
+            case: kind,
+            ignore_extension,
+            ..Default::default()
+        };
+
+        let result = case(input, rule);
+        assert_eq!(result, expected);
+    }
+
+    // 测试样例：保留已有的全大写缩写
+    #[rstest]
+    #[case("the nasa report.pdf", true, "The Nasa Report.pdf")]
+    #[case("the NASA report.pdf", true, "The NASA Report.pdf")]
+    #[case("the NASA report.pdf", false, "The Nasa Report.pdf")]
+    #[case("A TV_HD-2 guide.txt", true, "A TV_HD-2 Guide.txt")]
+    #[case("MP3 player.txt", true, "MP3 Player.txt")]
+    fn test_case_title_preserve_acronyms(
+        #[case] input: &str,
+        #[case] preserve_acronyms: bool,
+        #[case] expected: &str,
+    ) {
+        let rule = CaseRule {
+            case: CaseKind::Title,
+            ignore_extension: true,
+            preserve_acronyms,
+        };
+
+        let result = case(input, rule);
+        assert_eq!(result, expected);
+    }
+}
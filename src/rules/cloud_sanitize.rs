@@ -0,0 +1,177 @@
+use super::common::split_name;
+use serde::{Deserialize, Serialize};
+
+/// 非法字符的替换字符
+const REPLACEMENT: char = '_';
+
+/// 云存储服务
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CloudService {
+    Sharepoint,
+    GoogleDrive,
+    Dropbox,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloudSanitizeRule {
+    /// 目标服务
+    pub service: CloudService,
+}
+
+/// 一个服务对文件名的限制
+struct Restrictions {
+    /// 不允许出现的字符
+    forbidden: &'static [char],
+    /// 保留名称（不区分大小写），与整个文件名或去掉扩展名的部分相同都不允许
+    reserved: &'static [&'static str],
+    /// 不允许出现的片段
+    forbidden_parts: &'static [&'static str],
+    /// 是否同时禁止 Windows 设备名（如 "CON"、"LPT1"）
+    device_names: bool,
+    /// 文件名的最大字符数
+    max_len: usize,
+    /// 不允许以空格开头、以空格或 "." 结尾
+    trim_edges: bool,
+}
+
+/// Windows 设备名
+const DEVICE_NAMES: [&str; 22] = [
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+impl CloudService {
+    fn restrictions(self) -> Restrictions {
+        match self {
+            CloudService::Sharepoint => Restrictions {
+                forbidden: &[
+                    '~', '#', '%', '&', '*', '{', '}', '\\', ':', '<', '>', '?', '/', '+', '|', '"',
+                ],
+                reserved: &[".lock", "desktop.ini", "COM0", "LPT0"],
+                forbidden_parts: &["_vti_"],
+                device_names: true,
+                max_len: 255,
+                trim_edges: true,
+            },
+            CloudService::GoogleDrive => Restrictions {
+                forbidden: &['/'],
+                reserved: &[],
+                forbidden_parts: &[],
+                device_names: false,
+                max_len: 255,
+                trim_edges: false,
+            },
+            CloudService::Dropbox => Restrictions {
+                forbidden: &['/', '\\', '<', '>', ':', '"', '|', '?', '*'],
+                reserved: &["desktop.ini", "thumbs.db", ".ds_store", ".dropbox"],
+                forbidden_parts: &[],
+                device_names: true,
+                max_len: 255,
+                trim_edges: true,
+            },
+        }
+    }
+}
+
+/// 按目标云存储服务的规定整理文件名：非法字符替换为 "_"，去掉不允许的片段和首尾字符，
+/// 保留名称后加 "_"，过长时截断扩展名之前的部分
+pub fn cloud_sanitize(old_text: &str, rule: CloudSanitizeRule) -> String {
+    let restrictions = rule.service.restrictions();
+
+    let mut name: String = old_text
+        .chars()
+        .map(|c| {
+            if restrictions.forbidden.contains(&c) {
+                REPLACEMENT
+            } else {
+                c
+            }
+        })
+        .collect();
+    for part in restrictions.forbidden_parts {
+        // 逐次替换，直到替换后不再出现新的片段
+        while name.contains(part) {
+            name = name.replace(part, part.trim_start_matches(REPLACEMENT));
+        }
+    }
+    if restrictions.trim_edges {
+        name = name
+            .trim_start_matches(' ')
+            .trim_end_matches([' ', '.'])
+            .to_string();
+    }
+
+    let (stem, extension) = split_name(&name, true);
+    let is_reserved = |s: &str| {
+        restrictions
+            .reserved
+            .iter()
+            .chain(DEVICE_NAMES.iter().filter(|_| restrictions.device_names))
+            .any(|r| r.eq_ignore_ascii_case(s))
+    };
+    let stem = if is_reserved(&name) || is_reserved(&stem) {
+        format!("{}{}", stem, REPLACEMENT)
+    } else {
+        stem
+    };
+
+    let max_stem = restrictions
+        .max_len
+        .saturating_sub(extension.chars().count());
+    let stem: String = stem.chars().take(max_stem).collect();
+    format!("{}{}", stem, extension)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：SharePoint 的非法字符、保留名称、"_vti_" 片段和首尾字符
+    #[rstest]
+    #[case("Q1 ~budget #2 & notes?.xlsx", "Q1 _budget _2 _ notes_.xlsx")]
+    #[case("50% {draft}.docx", "50_ _draft_.docx")]
+    #[case("a+b|c\"d.txt", "a_b_c_d.txt")]
+    #[case("CON.txt", "CON_.txt")]
+    #[case("desktop.ini", "desktop_.ini")]
+    #[case(".lock", ".lock_")]
+    #[case("site_vti_cnf.html", "sitevti_cnf.html")]
+    #[case("  report.  ", "report")]
+    #[case("console.txt", "console.txt")]
+    fn test_cloud_sanitize_sharepoint(#[case] input: &str, #[case] expected: &str) {
+        let rule = CloudSanitizeRule {
+            service: CloudService::Sharepoint,
+        };
+
+        let result = cloud_sanitize(input, rule);
+        assert_eq!(result, expected);
+    }
+
+    // 测试样例：不同服务的限制不同
+    #[rstest]
+    #[case(CloudService::GoogleDrive, "a:b #1?.txt", "a:b #1?.txt")]
+    #[case(CloudService::GoogleDrive, "a/b.txt", "a_b.txt")]
+    #[case(CloudService::Dropbox, "a:b #1?.txt", "a_b #1_.txt")]
+    #[case(CloudService::Dropbox, "Thumbs.db", "Thumbs_.db")]
+    #[case(CloudService::Dropbox, "AUX.log", "AUX_.log")]
+    fn test_cloud_sanitize_services(
+        #[case] service: CloudService,
+        #[case] input: &str,
+        #[case] expected: &str,
+    ) {
+        let result = cloud_sanitize(input, CloudSanitizeRule { service });
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_cloud_sanitize_max_len() {
+        let input = format!("{}.txt", "a".repeat(300));
+        let rule = CloudSanitizeRule {
+            service: CloudService::Sharepoint,
+        };
+
+        let result = cloud_sanitize(&input, rule);
+        assert_eq!(result.chars().count(), 255);
+        assert!(result.ends_with("a.txt"));
+    }
+}
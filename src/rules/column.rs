@@ -0,0 +1,107 @@
+use super::common::split_name;
+use serde::{Deserialize, Serialize};
+
+/// 列内对齐方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ColumnAlign {
+    #[default]
+    Left,
+    Right,
+    Center,
+}
+
+/// 一列的格式
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColumnSpec {
+    /// 列宽（字符数），字段更长时保持原样，不截断
+    pub width: usize,
+    /// 对齐方式
+    #[serde(default)]
+    pub align: ColumnAlign,
+    /// 填充字符
+    pub fill: char,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColumnRule {
+    /// 每列的格式，依次对应各个字段；多出的字段保持原样
+    pub columns: Vec<ColumnSpec>,
+    /// 字段之间的分隔符
+    pub delimiter: char,
+}
+
+impl ColumnSpec {
+    fn pad(&self, field: &str) -> String {
+        let missing = self.width.saturating_sub(field.chars().count());
+        let (left, right) = match self.align {
+            ColumnAlign::Left => (0, missing),
+            ColumnAlign::Right => (missing, 0),
+            ColumnAlign::Center => (missing / 2, missing - missing / 2),
+        };
+        let fill = |n: usize| self.fill.to_string().repeat(n);
+        format!("{}{}{}", fill(left), field, fill(right))
+    }
+}
+
+/// 按 delimiter 把去掉扩展名后的文件名拆分为字段，按各列的宽度和对齐方式填充后重新拼接，
+/// 使表格式的文件名在目录列表中对齐
+pub fn column(old_text: &str, rule: ColumnRule) -> String {
+    let (name_to_process, extension_to_append) = split_name(old_text, true);
+
+    let fields: Vec<String> = name_to_process
+        .split(rule.delimiter)
+        .enumerate()
+        .map(|(index, field)| match rule.columns.get(index) {
+            Some(spec) => spec.pad(field),
+            None => field.to_string(),
+        })
+        .collect();
+    format!(
+        "{}{}",
+        fields.join(&rule.delimiter.to_string()),
+        extension_to_append
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn columns() -> Vec<ColumnSpec> {
+        vec![
+            ColumnSpec {
+                width: 4,
+                align: ColumnAlign::Right,
+                fill: '0',
+            },
+            ColumnSpec {
+                width: 8,
+                align: ColumnAlign::Left,
+                fill: '_',
+            },
+            ColumnSpec {
+                width: 5,
+                align: ColumnAlign::Center,
+                fill: '-',
+            },
+        ]
+    }
+
+    // 测试样例：三列分别右对齐、左对齐、居中，过长和多出的字段保持原样
+    #[rstest]
+    #[case("7 intro a.mp3", "0007 intro___ --a--.mp3")]
+    #[case("12 chapter ab.mp3", "0012 chapter_ -ab--.mp3")]
+    #[case("12345 epilogue_extra abcdefg.mp3", "12345 epilogue_extra abcdefg.mp3")]
+    #[case("3 x y z.mp3", "0003 x_______ --y-- z.mp3")]
+    #[case("3", "0003")]
+    fn test_column(#[case] input: &str, #[case] expected: &str) {
+        let rule = ColumnRule {
+            columns: columns(),
+            delimiter: ' ',
+        };
+
+        let result = column(input, rule);
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,183 @@
+use super::error::RuleError;
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
+/// 插入内容的位置
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InsertPosition {
+    /// 插入到文件名开头
+    Prefix,
+    /// 插入到文件名末尾（扩展名之前）
+    Suffix,
+}
+
+/// 编译用户提供的正则，失败时返回带有原始模式的 RuleError
+pub(crate) fn build_regex(pattern: &str, case_sensitive: bool) -> Result<Regex, RuleError> {
+    RegexBuilder::new(pattern)
+        .case_insensitive(!case_sensitive)
+        .build()
+        .map_err(|e| RuleError::InvalidPattern {
+            pattern: pattern.to_string(),
+            reason: e.to_string(),
+        })
+}
+
+/// 把字节数格式化为二进制单位的可读形式，如 512B、1.5KiB、1MiB
+pub(crate) fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 6] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    let rounded = (value * 10.0).round() / 10.0;
+    if rounded.fract() == 0.0 {
+        format!("{}{}", rounded as u64, UNITS[unit])
+    } else {
+        format!("{:.1}{}", rounded, UNITS[unit])
+    }
+}
+
+/// 去掉字母上的重音等附加符号，如 "é" -> "e"。
+/// 无法分解的常见拉丁字母按惯例转写，如 "ß" -> "ss"、"ø" -> "o"
+pub(crate) fn remove_accents(text: &str) -> String {
+    let mut result = String::new();
+    for c in text.nfd().filter(|&c| !is_combining_mark(c)) {
+        match c {
+            'ß' => result.push_str("ss"),
+            'æ' => result.push_str("ae"),
+            'Æ' => result.push_str("AE"),
+            'œ' => result.push_str("oe"),
+            'Œ' => result.push_str("OE"),
+            'ø' => result.push('o'),
+            'Ø' => result.push('O'),
+            'đ' => result.push('d'),
+            'Đ' => result.push('D'),
+            'ł' => result.push('l'),
+            'Ł' => result.push('L'),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// 用分隔符把 text 插入到文件名开头或末尾，ignore_extension 时插入到扩展名之前
+pub(crate) fn insert_text(
+    old_text: &str,
+    text: &str,
+    position: InsertPosition,
+    separator: &str,
+    ignore_extension: bool,
+) -> String {
+    let (name_to_process, extension_to_append) = split_name(old_text, ignore_extension);
+    match position {
+        InsertPosition::Prefix => format!(
+            "{}{}{}{}",
+            text, separator, name_to_process, extension_to_append
+        ),
+        InsertPosition::Suffix => format!(
+            "{}{}{}{}",
+            name_to_process, separator, text, extension_to_append
+        ),
+    }
+}
+
+/// 在 Windows 或常见文件系统中不能出现在文件名里的字符
+const ILLEGAL_CHARS: [char; 9] = ['<', '>', ':', '"', '/', '\\', '|', '?', '*'];
+
+/// 把任意文本整理为可以安全放进文件名的片段：
+/// 连续空白替换为单个 "_"，去掉非法字符和控制字符
+pub(crate) fn sanitize_component(text: &str) -> String {
+    let cleaned: String = text
+        .chars()
+        .filter(|c| !ILLEGAL_CHARS.contains(c) && !c.is_control())
+        .collect();
+    cleaned.split_whitespace().collect::<Vec<_>>().join("_")
+}
+
+/// 根据 ignore_extension 把文件名拆分为 "要处理的部分" 和 "要追加的扩展名"
+pub(crate) fn split_name(old_text: &str, ignore_extension: bool) -> (String, String) {
+    if !ignore_extension {
+        // 不忽略扩展名，则整个 old_text 都是要处理的部分
+        return (old_text.to_string(), String::new());
+    }
+
+    let path_obj = Path::new(old_text);
+
+    // file_stem() 获取文件名中最后一个点之前的部分。
+    // 例如: "archive.tar.gz" -> "archive.tar"
+    //       ".bashrc" -> ".bashrc" (因为没有被识别为传统意义的扩展名)
+    //       "nodot" -> "nodot"
+    //       "" -> None
+    //       "/" -> None
+    let base_name = path_obj
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_else(|| old_text.to_string()); // 如果没有 stem (如 "" 或 "/"), 则处理整个 old_text
+
+    // 只有当 Path 对象能同时识别出 stem 和 extension 时，我们才分离扩展名
+    // 例如，对于 ".bashrc"，file_stem() 是 ".bashrc"，extension() 是 None。
+    // 我们不希望将其错误地拆分为 name="" 和 ext=".bashrc"
+    let ext_suffix = if path_obj.file_stem().is_some() && path_obj.extension().is_some() {
+        path_obj
+            .extension()
+            .map(|e| format!(".{}", e.to_string_lossy()))
+            .unwrap_or_default() // 理论上如果外层条件满足，这里总是 Some
+    } else {
+        String::new() // 没有可分离的扩展名，或者不应分离
+    };
+    (base_name, ext_suffix)
+}
+
+/// 与 split_name 相同，但全为数字的 "扩展名"（如 "v1.2.10" 中的 "10"、"05.03.2023" 中的 "2023"）
+/// 视为文件名的一部分，供处理带点数字的规则使用
+pub(crate) fn split_name_numeric(old_text: &str, ignore_extension: bool) -> (String, String) {
+    let (stem, extension) = split_name(old_text, ignore_extension);
+    if extension
+        .strip_prefix('.')
+        .is_some_and(|e| e.chars().all(|c| c.is_ascii_digit()))
+    {
+        (old_text.to_string(), String::new())
+    } else {
+        (stem, extension)
+    }
+}
+
+/// 渲染带 `{占位符}` 的模板，每个占位符（花括号之间的文本）交给 lookup 求值；
+/// `{{` 和 `}}` 表示字面的花括号。占位符缺少结尾的 `}` 时返回 UnterminatedToken
+pub(crate) fn render_template(
+    template: &str,
+    mut lookup: impl FnMut(&str) -> Result<String, RuleError>,
+) -> Result<String, RuleError> {
+    let mut result = String::new();
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                result.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                result.push('}');
+            }
+            '{' => {
+                let mut token = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => token.push(c),
+                        None => return Err(RuleError::UnterminatedToken(template.to_string())),
+                    }
+                }
+                result.push_str(&lookup(&token)?);
+            }
+            c => result.push(c),
+        }
+    }
+    Ok(result)
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// 整批文件共享的上下文信息，供需要文件名以外输入的规则使用
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RuleContext {
+    /// 文件来源的压缩包或卷名，如 "photos_2023.zip"
+    #[serde(default)]
+    pub archive_name: Option<String>,
+    /// 调用方为整批文件传入的常量，如 "project" -> "ACME"
+    #[serde(default)]
+    pub context: HashMap<String, String>,
+}
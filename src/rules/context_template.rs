@@ -0,0 +1,81 @@
+use super::common::{render_template, split_name};
+use super::context::RuleContext;
+use super::error::RuleError;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContextTemplateRule {
+    /// 新文件名（不含扩展名）的模板，`{变量}` 从 RuleContext::context 中取值，
+    /// `{{` 和 `}}` 表示字面的花括号
+    pub pattern: String,
+}
+
+/// 用上下文中的变量渲染模板，结果作为新的文件名，扩展名保持不变。
+/// 模板中的变量在上下文中不存在时返回 UnknownToken，占位符缺少结尾的 `}` 时返回 UnterminatedToken
+pub fn context_template(
+    old_text: &str,
+    rule: ContextTemplateRule,
+    context: &RuleContext,
+) -> Result<String, RuleError> {
+    let (_, extension_to_append) = split_name(old_text, true);
+
+    let result = render_template(&rule.pattern, |token| {
+        context
+            .context
+            .get(token)
+            .cloned()
+            .ok_or_else(|| RuleError::UnknownToken(token.to_string()))
+    })?;
+    Ok(format!("{}{}", result, extension_to_append))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn context() -> RuleContext {
+        RuleContext {
+            context: [("project", "ACME"), ("year", "2024")]
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    // 测试样例：变量替换与花括号转义
+    #[rstest]
+    #[case("{project}_report", "draft.docx", "ACME_report.docx")]
+    #[case("{project}-{year}", "scan", "ACME-2024")]
+    #[case("{{{project}}}", "a.txt", "{ACME}.txt")]
+    #[case("static", "a.txt", "static.txt")]
+    fn test_context_template(#[case] pattern: &str, #[case] input: &str, #[case] expected: &str) {
+        let rule = ContextTemplateRule {
+            pattern: pattern.to_string(),
+        };
+
+        let result = context_template(input, rule, &context()).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_context_template_unknown_var() {
+        let rule = ContextTemplateRule {
+            pattern: "{project}_{client}".to_string(),
+        };
+
+        let result = context_template("a.txt", rule, &context());
+        assert!(matches!(result, Err(RuleError::UnknownToken(t)) if t == "client"));
+    }
+
+    #[test]
+    fn test_context_template_unterminated() {
+        let rule = ContextTemplateRule {
+            pattern: "{project".to_string(),
+        };
+
+        let result = context_template("a.txt", rule, &context());
+        assert!(matches!(result, Err(RuleError::UnterminatedToken(t)) if t == "{project"));
+    }
+}
@@ -0,0 +1,81 @@
+use super::common::split_name_numeric;
+use super::date_reformat::find_date;
+use serde::{Deserialize, Serialize};
+
+/// 日期与其余部分之间可能出现的分隔符
+const SEPARATORS: [char; 4] = [' ', '_', '-', '.'];
+
+/// 可识别的日期格式，按顺序优先
+const DATE_FORMATS: [&str; 11] = [
+    "%Y-%m-%d", "%Y.%m.%d", "%Y_%m_%d", "%Y%m%d", "%d %b %Y", "%d %B %Y", "%b %d %Y", "%B %d %Y",
+    "%d-%m-%Y", "%d.%m.%Y", "%d_%m_%Y",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DatePrefixNormalizeRule {
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+}
+
+/// 识别文件名中的日期，改写为 ISO 格式（YYYY-MM-DD）并移到开头，使文件按时间排序。
+/// 日期与其余部分之间沿用原来紧邻日期的分隔符，没有时使用空格；没有日期的文件保持原样。
+/// 没有扩展名时，结尾的数字（如 "notes 2023.01.05" 中的 "05"）不会被当成扩展名
+pub fn date_prefix_normalize(old_text: &str, rule: DatePrefixNormalizeRule) -> String {
+    let (name_to_process, extension_to_append) =
+        split_name_numeric(old_text, rule.ignore_extension);
+
+    let formats: Vec<String> = DATE_FORMATS.iter().map(|f| f.to_string()).collect();
+    let Some((range, date)) = find_date(&name_to_process, &formats) else {
+        return old_text.to_string();
+    };
+
+    let before = &name_to_process[..range.start];
+    let after = &name_to_process[range.end..];
+    let separator = after
+        .chars()
+        .next()
+        .or_else(|| before.chars().next_back())
+        .filter(|c| SEPARATORS.contains(c))
+        .unwrap_or(' ');
+    let rest = match (
+        before.trim_end_matches(SEPARATORS),
+        after.trim_start_matches(SEPARATORS),
+    ) {
+        ("", after) => after.to_string(),
+        (before, "") => before.to_string(),
+        (before, after) => format!("{}{}{}", before, separator, after),
+    };
+
+    let iso = date.format("%Y-%m-%d");
+    if rest.is_empty() {
+        format!("{}{}", iso, extension_to_append)
+    } else {
+        format!("{}{}{}{}", iso, separator, rest, extension_to_append)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：不同格式、不同位置的日期统一改写到开头
+    #[rstest]
+    #[case("5 Jan 2023 notes.txt", "2023-01-05 notes.txt")]
+    #[case("notes 2023-01-05.txt", "2023-01-05 notes.txt")]
+    #[case("notes_05.01.2023_final.md", "2023-01-05_notes_final.md")]
+    #[case("2023-01-05 notes.txt", "2023-01-05 notes.txt")]
+    #[case("IMG_20230105.jpg", "2023-01-05_IMG.jpg")]
+    #[case("January 5 2023.pdf", "2023-01-05.pdf")]
+    #[case("meeting notes.txt", "meeting notes.txt")]
+    #[case("notes 2023.01.05", "2023-01-05 notes")]
+    #[case("notes 05.01.2023", "2023-01-05 notes")]
+    fn test_date_prefix_normalize(#[case] input: &str, #[case] expected: &str) {
+        let rule = DatePrefixNormalizeRule {
+            ignore_extension: true,
+        };
+
+        let result = date_prefix_normalize(input, rule);
+        assert_eq!(result, expected);
+    }
+}
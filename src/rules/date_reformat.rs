@@ -0,0 +1,169 @@
+use super::common::split_name_numeric;
+use chrono::format::{Item, StrftimeItems};
+use chrono::{NaiveDate, NaiveDateTime};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+use std::ops::Range;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DateReformatRule {
+    /// 可识别的输入日期格式（chrono strftime 语法），按顺序尝试
+    pub input_formats: Vec<String>,
+    /// 输出日期格式（chrono strftime 语法）
+    pub output_format: String,
+}
+
+/// 把 strftime 格式转换为用于在文件名中定位候选日期的正则。
+/// 只支持日期相关的常用占位符，遇到不支持的占位符返回 None
+pub(crate) fn format_to_regex(format: &str) -> Option<String> {
+    let mut pattern = String::new();
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            pattern.push_str(&regex::escape(&c.to_string()));
+            continue;
+        }
+        let part = match chars.next()? {
+            'Y' => r"\d{4}",
+            'y' | 'H' | 'M' | 'S' => r"\d{2}",
+            'm' | 'd' => r"\d{1,2}",
+            'e' => r"\s?\d{1,2}",
+            'j' => r"\d{3}",
+            'b' | 'h' => r"[A-Za-z]{3}",
+            'B' => r"[A-Za-z]+",
+            '%' => "%",
+            _ => return None,
+        };
+        pattern.push_str(part);
+    }
+    Some(pattern)
+}
+
+/// 在文本中查找第一个能按任一格式解析的日期，格式按顺序优先。
+/// 为避免截取更长数字串的一部分，匹配两侧不能紧邻数字
+pub(crate) fn find_date(text: &str, formats: &[String]) -> Option<(Range<usize>, NaiveDate)> {
+    for format in formats {
+        let Some(pattern) = format_to_regex(format) else {
+            continue;
+        };
+        let Ok(re) = Regex::new(&pattern) else {
+            continue;
+        };
+        for m in re.find_iter(text) {
+            let before_is_digit = text[..m.start()]
+                .chars()
+                .next_back()
+                .is_some_and(|c| c.is_ascii_digit());
+            let after_is_digit = text[m.end()..]
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_ascii_digit());
+            if before_is_digit || after_is_digit {
+                continue;
+            }
+            if let Ok(date) = NaiveDate::parse_from_str(m.as_str(), format) {
+                return Some((m.range(), date));
+            }
+        }
+    }
+    None
+}
+
+/// 把 strftime 格式解析为 chrono 的格式项，格式非法时返回 None
+fn parse_format(format: &str) -> Option<Vec<Item<'_>>> {
+    let items: Vec<Item> = StrftimeItems::new(format).collect();
+    if items.iter().any(|item| matches!(item, Item::Error)) {
+        return None;
+    }
+    Some(items)
+}
+
+/// 按 strftime 格式输出日期。格式非法或引用了日期中没有的字段（如 %H）时返回 None，
+/// 避免 chrono 在 Display 时报错导致 panic
+pub(crate) fn format_date(date: NaiveDate, format: &str) -> Option<String> {
+    let items = parse_format(format)?;
+    let mut output = String::new();
+    write!(output, "{}", date.format_with_items(items.into_iter())).ok()?;
+    Some(output)
+}
+
+/// 按 strftime 格式输出日期时间，规则同 format_date
+pub(crate) fn format_datetime(datetime: NaiveDateTime, format: &str) -> Option<String> {
+    let items = parse_format(format)?;
+    let mut output = String::new();
+    write!(output, "{}", datetime.format_with_items(items.into_iter())).ok()?;
+    Some(output)
+}
+
+/// 没有扩展名时，结尾的年份（如 "report 05.01.2023" 中的 "2023"）不会被当成扩展名
+pub fn date_reformat(old_text: &str, rule: DateReformatRule) -> String {
+    let (name_to_process, extension_to_append) = split_name_numeric(old_text, true);
+
+    let Some((range, date)) = find_date(&name_to_process, &rule.input_formats) else {
+        return old_text.to_string(); // 没有可识别的日期
+    };
+    let Some(formatted) = format_date(date, &rule.output_format) else {
+        return old_text.to_string();
+    };
+
+    format!(
+        "{}{}{}{}",
+        &name_to_process[..range.start],
+        formatted,
+        &name_to_process[range.end..],
+        extension_to_append
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：不同输入格式统一转换为 ISO 日期
+    #[rstest]
+    #[case("report 01-05-2023.pdf", &["%d-%m-%Y"], "report 2023-05-01.pdf")]
+    #[case("report 05.01.2023.pdf", &["%m.%d.%Y"], "report 2023-05-01.pdf")]
+    #[case("report 05.01.2023", &["%m.%d.%Y"], "report 2023-05-01")]
+    #[case("scan_1 May 2023.png", &["%d %b %Y"], "scan_2023-05-01.png")]
+    #[case("notes 20230501", &["%d-%m-%Y", "%Y%m%d"], "notes 2023-05-01")]
+    fn test_date_reformat(#[case] input: &str, #[case] formats: &[&str], #[case] expected: &str) {
+        let rule = DateReformatRule {
+            input_formats: formats.iter().map(|f| f.to_string()).collect(),
+            output_format: "%Y-%m-%d".to_string(),
+        };
+
+        let result = date_reformat(input, rule);
+        assert_eq!(result, expected);
+    }
+
+    // 测试样例：没有可识别的日期或日期非法时保持原样
+    #[rstest]
+    #[case("holiday photos.jpg", "%d-%m-%Y")]
+    #[case("report 32-05-2023.pdf", "%d-%m-%Y")]
+    #[case("id 1101-05-20231.txt", "%d-%m-%Y")]
+    fn test_date_reformat_untouched(#[case] input: &str, #[case] format: &str) {
+        let rule = DateReformatRule {
+            input_formats: vec![format.to_string()],
+            output_format: "%Y-%m-%d".to_string(),
+        };
+
+        let result = date_reformat(input, rule);
+        assert_eq!(result, input);
+    }
+
+    // 测试样例：输出格式非法或包含时间字段时保持原样
+    #[rstest]
+    #[case("%Y-%Q")]
+    #[case("%Y-%m-%d %H:%M")]
+    fn test_date_reformat_invalid_output_format(#[case] output_format: &str) {
+        let rule = DateReformatRule {
+            input_formats: vec!["%d-%m-%Y".to_string()],
+            output_format: output_format.to_string(),
+        };
+
+        let result = date_reformat("report 01-05-2023.pdf", rule);
+        assert_eq!(result, "report 01-05-2023.pdf");
+    }
+}
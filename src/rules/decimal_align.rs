@@ -0,0 +1,71 @@
+use super::common::split_name_numeric;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecimalAlignRule {
+    /// 整数部分补零后的最小宽度
+    pub int_width: usize,
+    /// 小数部分补零后的最小宽度，为 0 时不补小数部分
+    pub frac_width: usize,
+}
+
+/// 把文件名中的数字补齐为固定的整数位和小数位，如 "1.5" -> "01.50"，使其按字典序也能正确排序。
+/// 没有小数部分的数字会补上小数点；含有多个点的版本号保持原样；
+/// 全为数字的 "扩展名"（如 "1.5" 中的 "5"）视为小数部分
+pub fn decimal_align(old_text: &str, rule: DecimalAlignRule) -> String {
+    let (name_to_process, extension_to_append) = split_name_numeric(old_text, true);
+
+    let re = Regex::new(r"\d+(?:\.\d+)*").expect("数字正则是合法的");
+    let aligned = re.replace_all(&name_to_process, |caps: &regex::Captures| {
+        let token = &caps[0];
+        let (int_part, frac_part) = match token.split_once('.') {
+            Some((_, frac)) if frac.contains('.') => return token.to_string(),
+            Some((int_part, frac)) => (int_part, frac),
+            None => (token, ""),
+        };
+        let int_part = format!("{:0>width$}", int_part, width = rule.int_width);
+        if rule.frac_width == 0 && frac_part.is_empty() {
+            return int_part;
+        }
+        format!(
+            "{}.{:0<width$}",
+            int_part,
+            frac_part,
+            width = rule.frac_width
+        )
+    });
+
+    format!("{}{}", aligned, extension_to_append)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：补齐整数与小数部分，包括缺少小数部分的数字
+    #[rstest]
+    #[case("1.5", 2, 2, "01.50")]
+    #[case("10.25", 2, 2, "10.25")]
+    #[case("7", 2, 2, "07.00")]
+    #[case("1.5.txt", 3, 1, "001.5.txt")]
+    #[case("price 3.txt", 2, 2, "price 03.00.txt")]
+    #[case("price 3.txt", 2, 0, "price 03.txt")]
+    #[case("rate 0.125.csv", 1, 2, "rate 0.125.csv")]
+    #[case("app 1.2.3.zip", 2, 2, "app 1.2.3.zip")]
+    fn test_decimal_align(
+        #[case] input: &str,
+        #[case] int_width: usize,
+        #[case] frac_width: usize,
+        #[case] expected: &str,
+    ) {
+        let rule = DecimalAlignRule {
+            int_width,
+            frac_width,
+        };
+
+        let result = decimal_align(input, rule);
+        assert_eq!(result, expected);
+    }
+}
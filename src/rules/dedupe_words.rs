@@ -0,0 +1,73 @@
+use super::common::split_name;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DedupeWordsRule {
+    /// 单词之间的分隔符
+    pub separator: String,
+    /// 区分大小写
+    pub case_sensitive: bool,
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+}
+
+/// 移除紧邻重复的单词，只保留第一个，如 "report report final" -> "report final"。
+/// 不相邻的重复单词保持不变
+pub fn dedupe_words(old_text: &str, rule: DedupeWordsRule) -> String {
+    if rule.separator.is_empty() {
+        return old_text.to_string();
+    }
+    let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
+
+    let same = |a: &str, b: &str| {
+        if rule.case_sensitive {
+            a == b
+        } else {
+            a.to_lowercase() == b.to_lowercase()
+        }
+    };
+    let mut words: Vec<&str> = Vec::new();
+    for word in name_to_process.split(rule.separator.as_str()) {
+        // 连续分隔符会产生空单词：比较时跳过它们，移除重复单词时连同前面的空单词一起去掉
+        let previous = words.iter().rev().find(|w| !w.is_empty());
+        if !word.is_empty() && previous.is_some_and(|last| same(last, word)) {
+            while words.last().is_some_and(|w| w.is_empty()) {
+                words.pop();
+            }
+            continue;
+        }
+        words.push(word);
+    }
+
+    format!("{}{}", words.join(&rule.separator), extension_to_append)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：只移除相邻的重复单词
+    #[rstest]
+    #[case("report report final.pdf", " ", true, "report final.pdf")]
+    #[case("The the file.txt", " ", true, "The the file.txt")]
+    #[case("The the file.txt", " ", false, "The file.txt")]
+    #[case("a b a b.txt", " ", true, "a b a b.txt")]
+    #[case("copy_copy_copy_of_doc.txt", "_", true, "copy_of_doc.txt")]
+    #[case("two  spaces  spaces.txt", " ", true, "two  spaces.txt")]
+    fn test_dedupe_words(
+        #[case] input: &str,
+        #[case] separator: &str,
+        #[case] case_sensitive: bool,
+        #[case] expected: &str,
+    ) {
+        let rule = DedupeWordsRule {
+            separator: separator.to_string(),
+            case_sensitive,
+            ignore_extension: true,
+        };
+
+        let result = dedupe_words(input, rule);
+        assert_eq!(result, expected);
+    }
+}
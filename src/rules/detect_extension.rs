@@ -0,0 +1,100 @@
+use super::error::RuleError;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// 同一种文件类型的常见扩展名别名，左边为 infer 给出的扩展名
+const EXTENSION_ALIASES: [(&str, &str); 5] = [
+    ("jpg", "jpeg"),
+    ("jpg", "jpe"),
+    ("tif", "tiff"),
+    ("mp4", "m4v"),
+    ("mid", "midi"),
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DetectExtensionRule {
+    /// 只修正与内容不符的扩展名，已经匹配（含别名，如 .jpeg）的文件保持原样
+    pub only_wrong: bool,
+}
+
+fn extension_matches(current: &str, detected: &str) -> bool {
+    let current = current.to_lowercase();
+    current == detected
+        || EXTENSION_ALIASES
+            .iter()
+            .any(|&(canonical, alias)| canonical == detected && alias == current)
+}
+
+/// 根据文件头部的魔数识别文件类型，并把扩展名改为对应的扩展名。
+/// 无法识别的文件保持原样
+pub fn detect_extension(path: &Path, rule: DetectExtensionRule) -> Result<String, RuleError> {
+    let old_text = path
+        .file_name()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    let Some(kind) = infer::get_from_path(path)? else {
+        return Ok(old_text); // 未知类型
+    };
+    let detected = kind.extension();
+
+    let current = path.extension().map(|e| e.to_string_lossy().into_owned());
+    if rule.only_wrong
+        && current
+            .as_deref()
+            .is_some_and(|e| extension_matches(e, detected))
+    {
+        return Ok(old_text);
+    }
+
+    let stem = match current {
+        Some(_) => path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        None => old_text,
+    };
+    Ok(format!("{}.{}", stem, detected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use std::fs;
+
+    const PNG_HEADER: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+    const JPEG_HEADER: &[u8] = &[0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x10, b'J', b'F', b'I', b'F'];
+
+    // 测试样例：按内容修正扩展名
+    #[rstest]
+    #[case("photo.jpg", PNG_HEADER, true, "photo.png")]
+    #[case("photo", PNG_HEADER, true, "photo.png")]
+    #[case("photo.png", PNG_HEADER, true, "photo.png")]
+    #[case("photo.jpeg", JPEG_HEADER, true, "photo.jpeg")]
+    #[case("photo.JPG", JPEG_HEADER, true, "photo.JPG")]
+    #[case("photo.jpeg", JPEG_HEADER, false, "photo.jpg")]
+    #[case("notes.txt", b"just some text", false, "notes.txt")]
+    fn test_detect_extension(
+        #[case] name: &str,
+        #[case] content: &[u8],
+        #[case] only_wrong: bool,
+        #[case] expected: &str,
+    ) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(name);
+        fs::write(&path, content).unwrap();
+
+        let result = detect_extension(&path, DetectExtensionRule { only_wrong }).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_detect_extension_missing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let rule = DetectExtensionRule { only_wrong: true };
+
+        let result = detect_extension(&dir.path().join("missing.jpg"), rule);
+        assert!(matches!(result, Err(RuleError::Io(_))));
+    }
+}
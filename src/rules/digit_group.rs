@@ -0,0 +1,85 @@
+use super::common::split_name;
+use serde::{Deserialize, Serialize};
+
+/// 模式中代表一位数字的占位符
+const PLACEHOLDER: char = '#';
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DigitGroupRule {
+    /// 分组模式，'#' 代表一位数字，其余字符原样输出，如 "(###) ###-####"
+    pub pattern: String,
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+}
+
+/// 按模式重新排列一串数字。数字多于占位符时多出的数字接在末尾；
+/// 数字不足时在最后一个填上的占位符处截断，不留下多余的分隔符
+fn regroup(digits: &str, pattern: &str) -> String {
+    let mut digits = digits.chars();
+    let mut grouped = String::new();
+    let mut pending = String::new(); // 还没有确定是否要输出的字面字符
+    for c in pattern.chars() {
+        if c != PLACEHOLDER {
+            pending.push(c);
+            continue;
+        }
+        let Some(digit) = digits.next() else {
+            return grouped;
+        };
+        grouped.push_str(&pending);
+        pending.clear();
+        grouped.push(digit);
+    }
+    grouped.push_str(&pending);
+    grouped.extend(digits);
+    grouped
+}
+
+/// 把文件名中的第一串数字按模式重新分组，如 "5551234567" -> "(555) 123-4567"。
+/// 模式中没有占位符时保持原样
+pub fn digit_group(old_text: &str, rule: DigitGroupRule) -> String {
+    if !rule.pattern.contains(PLACEHOLDER) {
+        return old_text.to_string();
+    }
+    let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
+
+    let Some(start) = name_to_process.find(|c: char| c.is_ascii_digit()) else {
+        return old_text.to_string();
+    };
+    let end = name_to_process[start..]
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or(name_to_process.len(), |i| start + i);
+
+    format!(
+        "{}{}{}{}",
+        &name_to_process[..start],
+        regroup(&name_to_process[start..end], &rule.pattern),
+        &name_to_process[end..],
+        extension_to_append
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：数字恰好、过多、不足，以及没有数字
+    #[rstest]
+    #[case("5551234567", "(###) ###-####", "(555) 123-4567")]
+    #[case("call 5551234567.txt", "###-###-####", "call 555-123-4567.txt")]
+    #[case("555123456789.txt", "###-###-####", "555-123-456789.txt")]
+    #[case("12345.txt", "(###) ###-####", "(123) 45.txt")]
+    #[case("order 123 item 45.txt", "#-##", "order 1-23 item 45.txt")]
+    #[case("no digits.txt", "###-###", "no digits.txt")]
+    #[case("5551234567.txt", "---", "5551234567.txt")]
+    fn test_digit_group(#[case] input: &str, #[case] pattern: &str, #[case] expected: &str) {
+        let rule = DigitGroupRule {
+            pattern: pattern.to_string(),
+            ignore_extension: true,
+        };
+
+        let result = digit_group(input, rule);
+        assert_eq!(result, expected);
+    }
+}
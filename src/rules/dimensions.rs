@@ -0,0 +1,107 @@
+use super::common::{InsertPosition, insert_text, render_template};
+use super::error::RuleError;
+use image::ImageError;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DimensionsRule {
+    /// 尺寸的格式，`{width}`、`{height}` 为宽和高（像素），如 "{width}x{height}"；
+    /// `{{` 和 `}}` 表示字面的花括号
+    pub format: String,
+    /// 插入的位置
+    pub position: InsertPosition,
+    /// 尺寸与原文件名之间的分隔符
+    pub separator: String,
+}
+
+/// 读取图片的宽和高并插入到文件名中，如 "photo.png" -> "photo_1920x1080.png"。
+/// 只解析文件头，不解码整张图片；不是可识别的图片时保持原文件名
+pub fn dimensions(path: &Path, rule: DimensionsRule) -> Result<String, RuleError> {
+    let old_text = path
+        .file_name()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    let (width, height) = match image::image_dimensions(path) {
+        Ok(size) => size,
+        Err(ImageError::IoError(e)) => return Err(e.into()),
+        Err(_) => return Ok(old_text), // 不支持的格式或文件头损坏
+    };
+    let size = render_template(&rule.format, |token| match token {
+        "width" => Ok(width.to_string()),
+        "height" => Ok(height.to_string()),
+        _ => Err(RuleError::UnknownToken(token.to_string())),
+    })?;
+    Ok(insert_text(
+        &old_text,
+        &size,
+        rule.position,
+        &rule.separator,
+        true,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::RgbImage;
+    use rstest::rstest;
+    use std::fs;
+
+    fn rule(format: &str, position: InsertPosition) -> DimensionsRule {
+        DimensionsRule {
+            format: format.to_string(),
+            position,
+            separator: "_".to_string(),
+        }
+    }
+
+    // 测试样例：不同格式、不同位置
+    #[rstest]
+    #[case("{width}x{height}", InsertPosition::Suffix, "photo_32x18.png")]
+    #[case("{width}x{height}", InsertPosition::Prefix, "32x18_photo.png")]
+    #[case("{height}p", InsertPosition::Suffix, "photo_18p.png")]
+    fn test_dimensions(
+        #[case] format: &str,
+        #[case] position: InsertPosition,
+        #[case] expected: &str,
+    ) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("photo.png");
+        RgbImage::new(32, 18).save(&path).unwrap();
+
+        let result = dimensions(&path, rule(format, position)).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_dimensions_not_an_image() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notes.png");
+        fs::write(&path, "not really a png").unwrap();
+
+        let result = dimensions(&path, rule("{width}x{height}", InsertPosition::Suffix)).unwrap();
+        assert_eq!(result, "notes.png");
+
+        let missing = dir.path().join("missing.png");
+        let result = dimensions(&missing, rule("{width}x{height}", InsertPosition::Suffix));
+        assert!(matches!(result, Err(RuleError::Io(_))));
+    }
+
+    #[test]
+    fn test_dimensions_unknown_token() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("photo.png");
+        RgbImage::new(4, 4).save(&path).unwrap();
+
+        let result = dimensions(&path, rule("{depth}", InsertPosition::Suffix));
+        assert!(matches!(result, Err(RuleError::UnknownToken(t)) if t == "depth"));
+
+        let result = dimensions(&path, rule("{width}x{height", InsertPosition::Suffix));
+        assert!(matches!(result, Err(RuleError::UnterminatedToken(_))));
+
+        let result = dimensions(&path, rule("{{{width}}}", InsertPosition::Suffix)).unwrap();
+        assert_eq!(result, "photo_{4}.png");
+    }
+}
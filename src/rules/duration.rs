@@ -0,0 +1,136 @@
+use super::common::{InsertPosition, insert_text};
+use super::error::RuleError;
+use lofty::prelude::AudioFile;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::path::Path;
+use std::time::Duration;
+
+/// 时长的输出格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DurationFormat {
+    /// 分:秒，如 "03:25"，超过一小时时分钟数继续累加，如 "75:00"。
+    /// 注意 ":" 不能出现在 Windows 文件名中
+    MinSec,
+    /// 总秒数，如 "205"
+    Seconds,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DurationRule {
+    /// 时长的输出格式
+    pub format: DurationFormat,
+    /// 插入的位置
+    pub position: InsertPosition,
+    /// 时长与原文件名之间的分隔符
+    pub separator: String,
+}
+
+impl DurationFormat {
+    fn format(self, duration: Duration) -> String {
+        // 四舍五入到整秒
+        let seconds = (duration.as_millis() as u64 + 500) / 1000;
+        match self {
+            DurationFormat::MinSec => format!("{:02}:{:02}", seconds / 60, seconds % 60),
+            DurationFormat::Seconds => seconds.to_string(),
+        }
+    }
+}
+
+/// 读取音频文件的时长并插入到文件名中。无法识别的音频格式保持原文件名
+pub fn duration(path: &Path, rule: DurationRule) -> Result<String, RuleError> {
+    let old_text = path
+        .file_name()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    let mut file = File::open(path)?;
+    let Ok(tagged) = lofty::read_from(&mut file) else {
+        return Ok(old_text); // 不是可识别的音频文件
+    };
+    let text = rule.format.format(tagged.properties().duration());
+
+    Ok(insert_text(
+        &old_text,
+        &text,
+        rule.position,
+        &rule.separator,
+        true,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use std::fs;
+
+    /// 生成指定时长的静音 WAV 文件（8kHz、单声道、8 位）
+    fn write_wav(path: &Path, seconds: u32) {
+        const SAMPLE_RATE: u32 = 8000;
+        let data_len = SAMPLE_RATE * seconds;
+        let mut wav = Vec::new();
+        wav.extend_from_slice(b"RIFF");
+        wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+        wav.extend_from_slice(b"WAVEfmt ");
+        wav.extend_from_slice(&16u32.to_le_bytes());
+        wav.extend_from_slice(&1u16.to_le_bytes()); // PCM
+        wav.extend_from_slice(&1u16.to_le_bytes()); // 声道数
+        wav.extend_from_slice(&SAMPLE_RATE.to_le_bytes());
+        wav.extend_from_slice(&SAMPLE_RATE.to_le_bytes()); // 每秒字节数
+        wav.extend_from_slice(&1u16.to_le_bytes()); // 块对齐
+        wav.extend_from_slice(&8u16.to_le_bytes()); // 位深
+        wav.extend_from_slice(b"data");
+        wav.extend_from_slice(&data_len.to_le_bytes());
+        wav.resize(wav.len() + data_len as usize, 0x80);
+        fs::write(path, wav).unwrap();
+    }
+
+    // 测试样例：两种时长格式 + 前/后缀
+    #[rstest]
+    #[case(DurationFormat::MinSec, InsertPosition::Suffix, "clip_00:03.wav")]
+    #[case(DurationFormat::Seconds, InsertPosition::Prefix, "3_clip.wav")]
+    fn test_duration(
+        #[case] format: DurationFormat,
+        #[case] position: InsertPosition,
+        #[case] expected: &str,
+    ) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("clip.wav");
+        write_wav(&path, 3);
+
+        let rule = DurationRule {
+            format,
+            position,
+            separator: "_".to_string(),
+        };
+
+        let result = duration(&path, rule).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_duration_format() {
+        let format = |f: DurationFormat, ms: u64| f.format(Duration::from_millis(ms));
+        assert_eq!(format(DurationFormat::MinSec, 205_400), "03:25");
+        assert_eq!(format(DurationFormat::MinSec, 4_499_600), "75:00");
+        assert_eq!(format(DurationFormat::Seconds, 59_500), "60");
+    }
+
+    #[test]
+    fn test_duration_not_audio() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notes.txt");
+        fs::write(&path, "hello").unwrap();
+
+        let rule = DurationRule {
+            format: DurationFormat::Seconds,
+            position: InsertPosition::Suffix,
+            separator: "_".to_string(),
+        };
+
+        assert_eq!(duration(&path, rule.clone()).unwrap(), "notes.txt");
+        let result = duration(&dir.path().join("missing.wav"), rule);
+        assert!(matches!(result, Err(RuleError::Io(_))));
+    }
+}
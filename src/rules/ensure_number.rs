@@ -0,0 +1,85 @@
+use super::common::{build_regex, split_name};
+use super::error::RuleError;
+use super::number::{NumberRule, number};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnsureNumberRule {
+    /// 判断文件是否已有编号的正则，匹配主干（不含扩展名）
+    pub pattern: String,
+    /// 为缺少编号的文件编号时使用的规则
+    pub number: NumberRule,
+}
+
+/// 只为主干不匹配 pattern 的文件编号，已有编号的文件保持原样，
+/// 计数器只在缺少编号的文件之间递增
+pub fn ensure_number(names: &[String], rule: EnsureNumberRule) -> Result<Vec<String>, RuleError> {
+    let re = build_regex(&rule.pattern, true)?;
+
+    let missing: Vec<usize> = names
+        .iter()
+        .enumerate()
+        .filter(|(_, name)| !re.is_match(&split_name(name, true).0))
+        .map(|(index, _)| index)
+        .collect();
+    let to_number: Vec<String> = missing.iter().map(|&i| names[i].clone()).collect();
+
+    let mut results = names.to_vec();
+    for (&index, numbered) in missing.iter().zip(number(&to_number, rule.number)?) {
+        results[index] = numbered;
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::InsertPosition;
+    use rstest::rstest;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    // 测试样例：四个文件中两个已有编号，只为另外两个编号
+    #[rstest]
+    #[case(
+        r"\d+",
+        &["scan 7.png", "cover.png", "scan 8.png", "back.png"],
+        &["scan 7.png", "cover_01.png", "scan 8.png", "back_02.png"]
+    )]
+    #[case(
+        r"^\d{2}_",
+        &["01_intro.md", "setup.md", "2023 notes.md", "02_usage.md"],
+        &["01_intro.md", "setup_01.md", "2023 notes_02.md", "02_usage.md"]
+    )]
+    fn test_ensure_number(
+        #[case] pattern: &str,
+        #[case] input: &[&str],
+        #[case] expected: &[&str],
+    ) {
+        let rule = EnsureNumberRule {
+            pattern: pattern.to_string(),
+            number: NumberRule {
+                padding: 2,
+                position: InsertPosition::Suffix,
+                separator: "_".to_string(),
+                ..Default::default()
+            },
+        };
+
+        let result = ensure_number(&names(input), rule).unwrap();
+        assert_eq!(result, names(expected));
+    }
+
+    #[test]
+    fn test_ensure_number_invalid_pattern() {
+        let rule = EnsureNumberRule {
+            pattern: "[".to_string(),
+            number: NumberRule::default(),
+        };
+
+        let result = ensure_number(&names(&["a.txt"]), rule);
+        assert!(matches!(result, Err(RuleError::InvalidPattern { .. })));
+    }
+}
@@ -0,0 +1,140 @@
+use std::{fmt, io};
+
+/// 规则执行过程中可能出现的错误
+#[derive(Debug)]
+pub enum RuleError {
+    /// 排序表达式中出现了未知的键
+    InvalidSortKey(String),
+    /// 无法编译的正则表达式
+    InvalidPattern { pattern: String, reason: String },
+    /// 替换表中第 index 项（从 0 开始）的正则表达式无法编译
+    InvalidTableEntry {
+        index: usize,
+        pattern: String,
+        reason: String,
+    },
+    /// 表达式（如 sed 风格的 s/模式/替换/标志）格式不正确
+    InvalidExpression { expression: String, reason: String },
+    /// 映射清单第 line 行（从 1 开始）格式错误或与前面的行重复
+    InvalidManifest { line: usize, reason: String },
+    /// 无法识别的时区名称
+    InvalidTimezone(String),
+    /// 没有内置的语言代码
+    UnknownLocale(String),
+    /// 模板中出现了未知的占位符
+    UnknownToken(String),
+    /// 模板中的占位符缺少结尾的 `}`，保存完整的模板
+    UnterminatedToken(String),
+    /// 映射表无法反转，列出对应多个键或为空的值
+    NotInvertible(Vec<String>),
+    /// 步长为 0 时编号落在跳过列表或冻结文件的编号上，无法顺延
+    UnskippableNumber(i64),
+    /// 缺少的编号超过上限，保存上限
+    TooManyMissing(u64),
+    /// 读取文件或元数据失败
+    Io(io::Error),
+}
+
+impl fmt::Display for RuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuleError::InvalidSortKey(key) => write!(f, "无效的排序键: {}", key),
+            RuleError::InvalidPattern { pattern, reason } => {
+                write!(f, "无效的正则表达式 {}: {}", pattern, reason)
+            }
+            RuleError::InvalidTableEntry {
+                index,
+                pattern,
+                reason,
+            } => write!(
+                f,
+                "替换表第 {} 项的正则表达式 {} 无效: {}",
+                index, pattern, reason
+            ),
+            RuleError::InvalidExpression { expression, reason } => {
+                write!(f, "无效的表达式 {}: {}", expression, reason)
+            }
+            RuleError::InvalidManifest { line, reason } => {
+                write!(f, "映射清单第 {} 行无效: {}", line, reason)
+            }
+            RuleError::InvalidTimezone(name) => write!(f, "无效的时区: {}", name),
+            RuleError::UnknownLocale(locale) => write!(f, "不支持的语言: {}", locale),
+            RuleError::UnknownToken(token) => write!(f, "未知的占位符: {{{}}}", token),
+            RuleError::UnterminatedToken(template) => {
+                write!(f, "模板 {} 中的占位符缺少结尾的 }}", template)
+            }
+            RuleError::NotInvertible(values) => {
+                write!(f, "映射表无法反转，以下值对应多个键或为空: {:?}", values)
+            }
+            RuleError::UnskippableNumber(n) => {
+                write!(f, "编号 {} 需要跳过，但步长为 0 无法顺延", n)
+            }
+            RuleError::TooManyMissing(limit) => {
+                write!(f, "缺少的编号超过 {} 个，请改用区间形式", limit)
+            }
+            RuleError::Io(e) => write!(f, "读取文件失败: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for RuleError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RuleError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for RuleError {
+    fn from(e: io::Error) -> Self {
+        RuleError::Io(e)
+    }
+}
+
+/// 解析管道表达式（如 `remove:"DSC_" | lower`）时可能出现的错误，
+/// stage 为出错的段号（从 1 开始）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// 第 position 个字符（从 0 开始）处语法错误，如引号未闭合
+    Syntax { position: usize, reason: String },
+    /// 两个 "|" 之间没有内容
+    EmptyStage { stage: usize },
+    /// 未知的规则名称
+    UnknownRule { stage: usize, name: String },
+    /// 规则不支持的参数，或多余的位置参数
+    UnknownArgument { stage: usize, name: String },
+    /// 缺少必需的参数
+    MissingArgument { stage: usize, name: String },
+    /// 参数值无法解析
+    InvalidValue {
+        stage: usize,
+        name: String,
+        value: String,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Syntax { position, reason } => {
+                write!(f, "第 {} 个字符处语法错误: {}", position, reason)
+            }
+            ParseError::EmptyStage { stage } => write!(f, "第 {} 段为空", stage),
+            ParseError::UnknownRule { stage, name } => {
+                write!(f, "第 {} 段: 未知的规则 {}", stage, name)
+            }
+            ParseError::UnknownArgument { stage, name } => {
+                write!(f, "第 {} 段: 未知的参数 {}", stage, name)
+            }
+            ParseError::MissingArgument { stage, name } => {
+                write!(f, "第 {} 段: 缺少参数 {}", stage, name)
+            }
+            ParseError::InvalidValue { stage, name, value } => {
+                write!(f, "第 {} 段: 参数 {} 的值 {} 无效", stage, name, value)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
@@ -0,0 +1,241 @@
+use super::common::{InsertPosition, insert_text, sanitize_component};
+use super::error::RuleError;
+use exif::{In, Reader, Tag, Value};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+/// 可以写入文件名的 EXIF 字段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExifField {
+    /// 相机型号，如 "Canon EOS R5"
+    Model,
+    /// 相机厂商，如 "Canon"
+    Make,
+    /// 镜头型号
+    LensModel,
+    /// 光圈值，输出为 "f2.8" 形式
+    FNumber,
+    /// 感光度，输出为 "ISO100" 形式
+    ISO,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExifTagRule {
+    /// 要写入的 EXIF 字段
+    pub tag: ExifField,
+    /// 插入的位置
+    pub position: InsertPosition,
+    /// 字段值与原文件名之间的分隔符
+    pub separator: String,
+    /// 把空白替换为 "_" 并去掉文件名中的非法字符
+    pub sanitize: bool,
+}
+
+impl ExifField {
+    fn tag(self) -> Tag {
+        match self {
+            ExifField::Model => Tag::Model,
+            ExifField::Make => Tag::Make,
+            ExifField::LensModel => Tag::LensModel,
+            ExifField::FNumber => Tag::FNumber,
+            ExifField::ISO => Tag::PhotographicSensitivity,
+        }
+    }
+
+    /// 把字段值格式化为适合放进文件名的文本，值为空或类型不符时返回 None
+    fn format(self, value: &Value) -> Option<String> {
+        let text = match (self, value) {
+            (ExifField::FNumber, Value::Rational(v)) => {
+                let f = v.first()?.to_f64();
+                if !f.is_finite() {
+                    return None;
+                }
+                // 四舍五入到一位小数，整数光圈不带小数点
+                format!("f{}", (f * 10.0).round() / 10.0)
+            }
+            (ExifField::ISO, _) => format!("ISO{}", value.get_uint(0)?),
+            (_, Value::Ascii(v)) => String::from_utf8_lossy(v.first()?)
+                .trim_end_matches('\0')
+                .trim()
+                .to_string(),
+            _ => return None,
+        };
+        (!text.is_empty()).then_some(text)
+    }
+}
+
+/// 读取文件的 EXIF 字段并插入到文件名中。
+/// 文件没有 EXIF 信息或缺少该字段时保持原文件名
+pub fn exif_tag(path: &Path, rule: ExifTagRule) -> Result<String, RuleError> {
+    let old_text = path
+        .file_name()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    let file = File::open(path)?;
+    let Ok(exif) = Reader::new().read_from_container(&mut BufReader::new(file)) else {
+        return Ok(old_text); // 没有可解析的 EXIF 信息
+    };
+    let Some(value) = exif
+        .get_field(rule.tag.tag(), In::PRIMARY)
+        .and_then(|field| rule.tag.format(&field.value))
+    else {
+        return Ok(old_text);
+    };
+
+    let value = if rule.sanitize {
+        sanitize_component(&value)
+    } else {
+        value
+    };
+    if value.is_empty() {
+        return Ok(old_text);
+    }
+    Ok(insert_text(
+        &old_text,
+        &value,
+        rule.position,
+        &rule.separator,
+        true,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use exif::experimental::Writer;
+    use exif::{Field, Rational};
+    use rstest::rstest;
+    use std::fs;
+    use std::io::Cursor;
+
+    /// 生成只包含 EXIF 段的最小 JPEG
+    fn write_jpeg(path: &Path, fields: &[Field]) {
+        let mut writer = Writer::new();
+        for field in fields {
+            writer.push_field(field);
+        }
+        let mut tiff = Cursor::new(Vec::new());
+        writer.write(&mut tiff, false).unwrap();
+        let tiff = tiff.into_inner();
+
+        let mut jpeg = vec![0xFF, 0xD8, 0xFF, 0xE1];
+        jpeg.extend_from_slice(&((tiff.len() + 8) as u16).to_be_bytes());
+        jpeg.extend_from_slice(b"Exif\0\0");
+        jpeg.extend_from_slice(&tiff);
+        jpeg.extend_from_slice(&[0xFF, 0xD9]);
+        fs::write(path, jpeg).unwrap();
+    }
+
+    fn fixture(dir: &Path) -> std::path::PathBuf {
+        let ascii = |tag, text: &str| Field {
+            tag,
+            ifd_num: In::PRIMARY,
+            value: Value::Ascii(vec![text.as_bytes().to_vec()]),
+        };
+        let fields = [
+            ascii(Tag::Make, "Canon"),
+            ascii(Tag::Model, "Canon EOS R5"),
+            ascii(Tag::LensModel, "RF24-105mm F4 L IS USM"),
+            Field {
+                tag: Tag::FNumber,
+                ifd_num: In::PRIMARY,
+                value: Value::Rational(vec![Rational { num: 28, denom: 10 }]),
+            },
+            Field {
+                tag: Tag::PhotographicSensitivity,
+                ifd_num: In::PRIMARY,
+                value: Value::Short(vec![400]),
+            },
+        ];
+        let path = dir.join("IMG_0001.jpg");
+        write_jpeg(&path, &fields);
+        path
+    }
+
+    // 测试样例：读取不同字段并插入到文件名
+    #[rstest]
+    #[case(ExifField::Make, InsertPosition::Prefix, false, "Canon_IMG_0001.jpg")]
+    #[case(
+        ExifField::Model,
+        InsertPosition::Suffix,
+        true,
+        "IMG_0001_Canon_EOS_R5.jpg"
+    )]
+    #[case(
+        ExifField::Model,
+        InsertPosition::Suffix,
+        false,
+        "IMG_0001_Canon EOS R5.jpg"
+    )]
+    #[case(
+        ExifField::LensModel,
+        InsertPosition::Suffix,
+        true,
+        "IMG_0001_RF24-105mm_F4_L_IS_USM.jpg"
+    )]
+    #[case(ExifField::FNumber, InsertPosition::Suffix, true, "IMG_0001_f2.8.jpg")]
+    #[case(ExifField::ISO, InsertPosition::Prefix, true, "ISO400_IMG_0001.jpg")]
+    fn test_exif_tag(
+        #[case] tag: ExifField,
+        #[case] position: InsertPosition,
+        #[case] sanitize: bool,
+        #[case] expected: &str,
+    ) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = fixture(dir.path());
+
+        let rule = ExifTagRule {
+            tag,
+            position,
+            separator: "_".to_string(),
+            sanitize,
+        };
+
+        let result = exif_tag(&path, rule).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    // 测试样例：缺少字段或没有 EXIF 信息时保持原文件名
+    #[test]
+    fn test_exif_tag_missing() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("scan.jpg");
+        write_jpeg(
+            &path,
+            &[Field {
+                tag: Tag::Make,
+                ifd_num: In::PRIMARY,
+                value: Value::Ascii(vec![b"Canon".to_vec()]),
+            }],
+        );
+        let plain = dir.path().join("notes.txt");
+        fs::write(&plain, "hello").unwrap();
+
+        let rule = ExifTagRule {
+            tag: ExifField::LensModel,
+            position: InsertPosition::Suffix,
+            separator: "_".to_string(),
+            sanitize: true,
+        };
+
+        assert_eq!(exif_tag(&path, rule.clone()).unwrap(), "scan.jpg");
+        assert_eq!(exif_tag(&plain, rule).unwrap(), "notes.txt");
+    }
+
+    #[test]
+    fn test_exif_tag_missing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let rule = ExifTagRule {
+            tag: ExifField::Make,
+            position: InsertPosition::Prefix,
+            separator: "_".to_string(),
+            sanitize: false,
+        };
+
+        let result = exif_tag(&dir.path().join("missing.jpg"), rule);
+        assert!(matches!(result, Err(RuleError::Io(_))));
+    }
+}
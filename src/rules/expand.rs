@@ -0,0 +1,138 @@
+use super::common::split_name;
+use super::error::RuleError;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExpandRule {
+    /// 缩写 -> 全称，区分大小写
+    pub dictionary: HashMap<String, String>,
+    /// 只替换独立的单词，如 "St" 不会匹配 "Stone" 中的 "St"
+    pub whole_word_only: bool,
+}
+
+impl ExpandRule {
+    /// 从词典文件读取缩写表，每行为 `缩写=全称`，空行和以 `#` 开头的行会被忽略
+    pub fn from_file(path: &Path, whole_word_only: bool) -> Result<Self, RuleError> {
+        let content = fs::read_to_string(path)?;
+        let mut dictionary = HashMap::new();
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            match line.split_once('=') {
+                Some((abbr, expansion)) if !abbr.trim().is_empty() => {
+                    dictionary.insert(abbr.trim().to_string(), expansion.trim().to_string());
+                }
+                _ => {
+                    return Err(RuleError::InvalidManifest {
+                        line: index + 1,
+                        reason: "应为 缩写=全称".to_string(),
+                    });
+                }
+            }
+        }
+        Ok(ExpandRule {
+            dictionary,
+            whole_word_only,
+        })
+    }
+}
+
+/// 把文件名中的缩写替换为词典中的全称，扩展名保持不变
+pub fn expand(old_text: &str, rule: ExpandRule) -> String {
+    let (name_to_process, extension_to_append) = split_name(old_text, true);
+
+    let mut keys: Vec<&String> = rule.dictionary.keys().filter(|k| !k.is_empty()).collect();
+    if keys.is_empty() {
+        return old_text.to_string();
+    }
+    // 长的缩写优先，避免 "St" 抢先匹配 "Str" 的前半部分
+    keys.sort_by(|a, b| b.chars().count().cmp(&a.chars().count()).then(a.cmp(b)));
+    let alternation = keys
+        .iter()
+        .map(|k| regex::escape(k))
+        .collect::<Vec<_>>()
+        .join("|");
+    let Ok(re) = Regex::new(&alternation) else {
+        return old_text.to_string();
+    };
+
+    // 文件名中常用 "_" 连接单词，所以这里用字母数字判断单词边界，而不是正则的 \b
+    let is_word_char = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+    let mut expanded = String::new();
+    let mut last = 0;
+    let mut pos = 0;
+    while let Some(m) = re.find_at(&name_to_process, pos) {
+        let before = name_to_process[..m.start()].chars().next_back();
+        let after = name_to_process[m.end()..].chars().next();
+        if rule.whole_word_only && (is_word_char(before) || is_word_char(after)) {
+            // 从下一个字符重新查找，以免漏掉从单词内部开始的匹配
+            pos = m.start()
+                + name_to_process[m.start()..]
+                    .chars()
+                    .next()
+                    .map_or(1, char::len_utf8);
+            continue;
+        }
+        expanded.push_str(&name_to_process[last..m.start()]);
+        expanded.push_str(&rule.dictionary[m.as_str()]);
+        last = m.end();
+        pos = m.end();
+    }
+    expanded.push_str(&name_to_process[last..]);
+    format!("{}{}", expanded, extension_to_append)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn dictionary() -> HashMap<String, String> {
+        [("St", "Street"), ("Rd", "Road"), ("Str", "Strasse")]
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    // 测试样例：是否要求单词边界
+    #[rstest]
+    #[case("12 Stone St.jpg", true, "12 Stone Street.jpg")]
+    #[case("12 Stone St.jpg", false, "12 Streetone Street.jpg")]
+    #[case("Main Rd_Str 5.png", true, "Main Road_Strasse 5.png")]
+    #[case("Rd2 Sta.png", true, "Rd2 Sta.png")]
+    #[case("Haupt Str 5.png", true, "Haupt Strasse 5.png")]
+    #[case("Main Rd.St", true, "Main Road.St")]
+    fn test_expand(#[case] input: &str, #[case] whole_word_only: bool, #[case] expected: &str) {
+        let rule = ExpandRule {
+            dictionary: dictionary(),
+            whole_word_only,
+        };
+
+        let result = expand(input, rule);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_expand_from_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("abbr.txt");
+        fs::write(&path, "# 街道缩写\nSt = Street\n\nAve=Avenue\n").unwrap();
+
+        let rule = ExpandRule::from_file(&path, true).unwrap();
+        assert_eq!(rule.dictionary.len(), 2);
+        assert_eq!(expand("5th Ave St.jpg", rule), "5th Avenue Street.jpg");
+
+        fs::write(&path, "St=Street\nbroken line\n").unwrap();
+        let result = ExpandRule::from_file(&path, true);
+        assert!(matches!(
+            result,
+            Err(RuleError::InvalidManifest { line: 2, .. })
+        ));
+    }
+}
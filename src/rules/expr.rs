@@ -0,0 +1,391 @@
+use super::common::split_name;
+use super::error::RuleError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExprRule {
+    /// 生成新文件名（不含扩展名）的表达式，如 `upper(substr(name,0,3)) + "_" + pad(index,3)`。
+    ///
+    /// - 变量：`name` 去掉扩展名的文件名，`ext` 不带点的扩展名，`index` 文件在批次中的序号（从 1 开始）
+    /// - 字面量：双引号字符串（`\"`、`\\` 转义）和非负整数
+    /// - 函数：`upper(s)`、`lower(s)`、`substr(s, start, len)`（按字符计）、
+    ///   `replace(s, from, to)`、`pad(s, width)`（左侧补 '0' 到 width 位）
+    /// - `+` 连接字符串，整数按十进制转换
+    pub expression: String,
+}
+
+/// 表达式中的变量
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Var {
+    Name,
+    Ext,
+    Index,
+}
+
+/// 表达式中的函数
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Func {
+    Upper,
+    Lower,
+    Substr,
+    Replace,
+    Pad,
+}
+
+impl Func {
+    fn from_name(name: &str) -> Option<Func> {
+        match name {
+            "upper" => Some(Func::Upper),
+            "lower" => Some(Func::Lower),
+            "substr" => Some(Func::Substr),
+            "replace" => Some(Func::Replace),
+            "pad" => Some(Func::Pad),
+            _ => None,
+        }
+    }
+
+    fn arity(self) -> usize {
+        match self {
+            Func::Upper | Func::Lower => 1,
+            Func::Pad => 2,
+            Func::Substr | Func::Replace => 3,
+        }
+    }
+}
+
+/// 解析后的语法树
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Str(String),
+    Int(i64),
+    Var(Var),
+    Call(Func, Vec<Expr>),
+    Concat(Vec<Expr>),
+}
+
+/// 求值结果
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Str(String),
+    Int(i64),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Str(s) => write!(f, "{}", s),
+            Value::Int(n) => write!(f, "{}", n),
+        }
+    }
+}
+
+/// 当前文件的变量取值
+struct Scope<'a> {
+    name: &'a str,
+    ext: &'a str,
+    index: usize,
+}
+
+/// 递归下降解析器，按字符位置报告错误
+struct Parser<'a> {
+    chars: Vec<char>,
+    pos: usize,
+    expression: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn new(expression: &'a str) -> Self {
+        Parser {
+            chars: expression.chars().collect(),
+            pos: 0,
+            expression,
+        }
+    }
+
+    fn error(&self, reason: String) -> RuleError {
+        RuleError::InvalidExpression {
+            expression: self.expression.to_string(),
+            reason: format!("位置 {}: {}", self.pos, reason),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.get(self.pos).is_some_and(|c| c.is_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.chars.get(self.pos).copied()
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), RuleError> {
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.pos += 1;
+                Ok(())
+            }
+            Some(c) => Err(self.error(format!("应为 '{}'，实际为 '{}'", expected, c))),
+            None => Err(self.error(format!("应为 '{}'，表达式意外结束", expected))),
+        }
+    }
+
+    /// expr := term ('+' term)*
+    fn parse(mut self) -> Result<Expr, RuleError> {
+        let expr = self.parse_concat()?;
+        if let Some(c) = self.peek() {
+            return Err(self.error(format!("多余的字符 '{}'", c)));
+        }
+        Ok(expr)
+    }
+
+    fn parse_concat(&mut self) -> Result<Expr, RuleError> {
+        let mut terms = vec![self.parse_term()?];
+        while self.peek() == Some('+') {
+            self.pos += 1;
+            terms.push(self.parse_term()?);
+        }
+        Ok(if terms.len() == 1 {
+            terms.pop().unwrap()
+        } else {
+            Expr::Concat(terms)
+        })
+    }
+
+    /// term := 字符串 | 整数 | 变量 | 函数名 '(' expr (',' expr)* ')'
+    fn parse_term(&mut self) -> Result<Expr, RuleError> {
+        match self.peek() {
+            Some('"') => self.parse_string(),
+            Some(c) if c.is_ascii_digit() => self.parse_int(),
+            Some(c) if c.is_ascii_alphabetic() || c == '_' => self.parse_ident(),
+            Some(c) => Err(self.error(format!("意外的字符 '{}'", c))),
+            None => Err(self.error("表达式意外结束".to_string())),
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<Expr, RuleError> {
+        self.pos += 1;
+        let mut value = String::new();
+        loop {
+            match self.chars.get(self.pos).copied() {
+                Some('"') => {
+                    self.pos += 1;
+                    return Ok(Expr::Str(value));
+                }
+                Some('\\') => match self.chars.get(self.pos + 1).copied() {
+                    Some(c @ ('"' | '\\')) => {
+                        value.push(c);
+                        self.pos += 2;
+                    }
+                    _ => return Err(self.error("无效的转义".to_string())),
+                },
+                Some(c) => {
+                    value.push(c);
+                    self.pos += 1;
+                }
+                None => return Err(self.error("字符串缺少结尾的 '\"'".to_string())),
+            }
+        }
+    }
+
+    fn parse_int(&mut self) -> Result<Expr, RuleError> {
+        let start = self.pos;
+        while self.chars.get(self.pos).is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        let digits: String = self.chars[start..self.pos].iter().collect();
+        digits
+            .parse()
+            .map(Expr::Int)
+            .map_err(|_| self.error(format!("整数 {} 超出范围", digits)))
+    }
+
+    fn parse_ident(&mut self) -> Result<Expr, RuleError> {
+        let start = self.pos;
+        while self
+            .chars
+            .get(self.pos)
+            .is_some_and(|c| c.is_ascii_alphanumeric() || *c == '_')
+        {
+            self.pos += 1;
+        }
+        let ident: String = self.chars[start..self.pos].iter().collect();
+
+        if self.peek() != Some('(') {
+            return match ident.as_str() {
+                "name" => Ok(Expr::Var(Var::Name)),
+                "ext" => Ok(Expr::Var(Var::Ext)),
+                "index" => Ok(Expr::Var(Var::Index)),
+                _ => Err(self.error(format!("未知的变量 {}", ident))),
+            };
+        }
+
+        let Some(func) = Func::from_name(&ident) else {
+            return Err(self.error(format!("未知的函数 {}", ident)));
+        };
+        self.pos += 1;
+        let mut args = Vec::new();
+        if self.peek() != Some(')') {
+            args.push(self.parse_concat()?);
+            while self.peek() == Some(',') {
+                self.pos += 1;
+                args.push(self.parse_concat()?);
+            }
+        }
+        self.expect(')')?;
+        if args.len() != func.arity() {
+            return Err(self.error(format!(
+                "{} 需要 {} 个参数，实际为 {} 个",
+                ident,
+                func.arity(),
+                args.len()
+            )));
+        }
+        Ok(Expr::Call(func, args))
+    }
+}
+
+impl ExprRule {
+    /// 求值时的错误同样以 InvalidExpression 报告
+    fn invalid(&self, reason: String) -> RuleError {
+        RuleError::InvalidExpression {
+            expression: self.expression.clone(),
+            reason,
+        }
+    }
+
+    fn eval(&self, expr: &Expr, scope: &Scope) -> Result<Value, RuleError> {
+        match expr {
+            Expr::Str(s) => Ok(Value::Str(s.clone())),
+            Expr::Int(n) => Ok(Value::Int(*n)),
+            Expr::Var(Var::Name) => Ok(Value::Str(scope.name.to_string())),
+            Expr::Var(Var::Ext) => Ok(Value::Str(scope.ext.to_string())),
+            Expr::Var(Var::Index) => Ok(Value::Int(scope.index as i64)),
+            Expr::Concat(terms) => {
+                let mut joined = String::new();
+                for term in terms {
+                    joined.push_str(&self.eval(term, scope)?.to_string());
+                }
+                Ok(Value::Str(joined))
+            }
+            Expr::Call(func, args) => {
+                let values = args
+                    .iter()
+                    .map(|arg| self.eval(arg, scope))
+                    .collect::<Result<Vec<_>, _>>()?;
+                self.call(*func, &values)
+            }
+        }
+    }
+
+    fn call(&self, func: Func, args: &[Value]) -> Result<Value, RuleError> {
+        let int_arg = |i: usize| match &args[i] {
+            Value::Int(n) if *n >= 0 => Ok(*n as usize),
+            other => {
+                Err(self.invalid(format!("第 {} 个参数应为非负整数，实际为 {}", i + 1, other)))
+            }
+        };
+        let text = args[0].to_string();
+        let result = match func {
+            Func::Upper => text.to_uppercase(),
+            Func::Lower => text.to_lowercase(),
+            Func::Substr => text.chars().skip(int_arg(1)?).take(int_arg(2)?).collect(),
+            Func::Replace => {
+                let from = args[1].to_string();
+                if from.is_empty() {
+                    text
+                } else {
+                    text.replace(&from, &args[2].to_string())
+                }
+            }
+            Func::Pad => {
+                let width = int_arg(1)?;
+                let len = text.chars().count();
+                format!("{}{}", "0".repeat(width.saturating_sub(len)), text)
+            }
+        };
+        Ok(Value::Str(result))
+    }
+}
+
+/// 解析表达式一次，再对每个文件求值，结果作为新的文件名，扩展名保持不变。
+/// 表达式无效或求值出错（如给 substr 传入字符串作为下标）时返回 InvalidExpression
+pub fn expr(names: &[String], rule: ExprRule) -> Result<Vec<String>, RuleError> {
+    let ast = Parser::new(&rule.expression).parse()?;
+
+    names
+        .iter()
+        .enumerate()
+        .map(|(i, old_text)| {
+            let (name_to_process, extension_to_append) = split_name(old_text, true);
+            let scope = Scope {
+                name: &name_to_process,
+                ext: extension_to_append.trim_start_matches('.'),
+                index: i + 1,
+            };
+            let value = rule.eval(&ast, &scope)?;
+            Ok(format!("{}{}", value, extension_to_append))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn rule(expression: &str) -> ExprRule {
+        ExprRule {
+            expression: expression.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_expr_upper_substr_pad() {
+        let inputs = names(&["holiday.jpg", "beach.png", "xy"]);
+
+        let result = expr(
+            &inputs,
+            rule(r#"upper(substr(name,0,3)) + "_" + pad(index,3)"#),
+        )
+        .unwrap();
+        assert_eq!(result, names(&["HOL_001.jpg", "BEA_002.png", "XY_003"]));
+    }
+
+    // 测试样例：变量、函数、转义与连接
+    #[rstest]
+    #[case("name + \"-\" + ext", "photo.jpg", "photo-jpg.jpg")]
+    #[case("replace(name, \" \", \"_\")", "my cat photo.jpg", "my_cat_photo.jpg")]
+    #[case("lower(name)", "PHOTO.JPG", "photo.JPG")]
+    #[case("substr(name, 2, 100)", "中文名字.txt", "名字.txt")]
+    #[case("\"say \\\"hi\\\"\"", "a.txt", "say \"hi\".txt")]
+    #[case("pad(name, 2)", "abc.txt", "abc.txt")]
+    #[case("index + 10", "a.txt", "110.txt")]
+    fn test_expr(#[case] expression: &str, #[case] input: &str, #[case] expected: &str) {
+        let result = expr(&names(&[input]), rule(expression)).unwrap();
+        assert_eq!(result, names(&[expected]));
+    }
+
+    // 测试样例：语法错误、未知标识符、参数数量与类型错误
+    #[rstest]
+    #[case("upper(name")]
+    #[case("name +")]
+    #[case("title(name)")]
+    #[case("stem")]
+    #[case("substr(name, 0)")]
+    #[case("substr(name, \"0\", 2)")]
+    #[case("\"unterminated")]
+    #[case("name name")]
+    fn test_expr_invalid(#[case] expression: &str) {
+        let result = expr(&names(&["a.txt"]), rule(expression));
+        assert!(
+            matches!(result, Err(RuleError::InvalidExpression { expression: e, .. }) if e == expression)
+        );
+    }
+}
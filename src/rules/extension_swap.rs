@@ -0,0 +1,78 @@
+use super::common::split_name;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExtSwapDir {
+    /// 把扩展名并入主干：photo.jpg -> photo_jpg
+    ExtToStem,
+    /// 把主干最后一个 "_" 之后的部分作为扩展名：photo_jpg -> photo.jpg
+    StemTailToExt,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtensionSwapRule {
+    /// 移动方向
+    pub direction: ExtSwapDir,
+}
+
+pub fn extension_swap(old_text: &str, rule: ExtensionSwapRule) -> String {
+    let (stem, extension) = split_name(old_text, true);
+
+    match rule.direction {
+        ExtSwapDir::ExtToStem => match extension.strip_prefix('.') {
+            Some(ext) => format!("{}_{}", stem, ext),
+            None => old_text.to_string(), // 没有扩展名
+        },
+        ExtSwapDir::StemTailToExt => match stem.rsplit_once('_') {
+            // 主干末尾的片段成为新扩展名，原扩展名被替换
+            Some((head, tail)) if !head.is_empty() && !tail.is_empty() => {
+                format!("{}.{}", head, tail)
+            }
+            _ => old_text.to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：两个方向的移动
+    #[rstest]
+    #[case("photo.jpg", ExtSwapDir::ExtToStem, "photo_jpg")]
+    #[case("archive.tar.gz", ExtSwapDir::ExtToStem, "archive.tar_gz")]
+    #[case("README", ExtSwapDir::ExtToStem, "README")]
+    #[case("photo_jpg", ExtSwapDir::StemTailToExt, "photo.jpg")]
+    #[case("my_photo_png.bin", ExtSwapDir::StemTailToExt, "my_photo.png")]
+    #[case("photo", ExtSwapDir::StemTailToExt, "photo")]
+    #[case("_jpg", ExtSwapDir::StemTailToExt, "_jpg")]
+    #[case("photo_", ExtSwapDir::StemTailToExt, "photo_")]
+    fn test_extension_swap(
+        #[case] input: &str,
+        #[case] direction: ExtSwapDir,
+        #[case] expected: &str,
+    ) {
+        let rule = ExtensionSwapRule { direction };
+
+        let result = extension_swap(input, rule);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_extension_swap_round_trip() {
+        let to_stem = extension_swap(
+            "photo.jpg",
+            ExtensionSwapRule {
+                direction: ExtSwapDir::ExtToStem,
+            },
+        );
+        let back = extension_swap(
+            &to_stem,
+            ExtensionSwapRule {
+                direction: ExtSwapDir::StemTailToExt,
+            },
+        );
+        assert_eq!(back, "photo.jpg");
+    }
+}
@@ -0,0 +1,105 @@
+use super::common::{sanitize_component, split_name};
+use super::error::RuleError;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FirstLineRule {
+    /// 新文件名（不含扩展名）的最大字符数
+    pub max_len: usize,
+    /// 去掉 Markdown 标题符号和非法字符，并把空白替换为 "_"
+    pub sanitize: bool,
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+}
+
+/// 读取文件的第一个非空行；不是 UTF-8 文本或无法读取时返回 None
+fn read_first_line(path: &Path) -> Option<String> {
+    let file = File::open(path).ok()?;
+    for line in BufReader::new(file).lines() {
+        let line = line.ok()?;
+        if line.contains('\0') {
+            return None; // 二进制文件
+        }
+        if !line.trim().is_empty() {
+            return Some(line.trim().to_string());
+        }
+    }
+    None
+}
+
+/// 以文本文件的第一个非空行作为新的文件名，如笔记文件的标题。
+/// 不是文本、无法读取或没有内容的文件保持原文件名
+pub fn first_line(path: &Path, rule: FirstLineRule) -> Result<String, RuleError> {
+    let old_text = path
+        .file_name()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    let Some(line) = read_first_line(path) else {
+        return Ok(old_text);
+    };
+    let line = if rule.sanitize {
+        sanitize_component(line.trim_start_matches('#'))
+    } else {
+        line
+    };
+    let stem: String = line.chars().take(rule.max_len).collect();
+    let stem = stem.trim_end_matches(['_', ' ', '.']);
+    if stem.is_empty() {
+        return Ok(old_text);
+    }
+
+    let (_, extension_to_append) = split_name(&old_text, rule.ignore_extension);
+    Ok(format!("{}{}", stem, extension_to_append))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use std::fs;
+
+    fn rule(max_len: usize, sanitize: bool) -> FirstLineRule {
+        FirstLineRule {
+            max_len,
+            sanitize,
+            ignore_extension: true,
+        }
+    }
+
+    // 测试样例：跳过空行、整理标题、按长度截断
+    #[rstest]
+    #[case("\n\n# Meeting Notes: Q3?\nbody\n", 50, true, "Meeting_Notes_Q3.md")]
+    #[case("# Meeting Notes: Q3?\n", 7, true, "Meeting.md")]
+    #[case("# Meeting Notes: Q3?\n", 8, true, "Meeting.md")]
+    #[case("Shopping list\n", 50, false, "Shopping list.md")]
+    #[case("\n  \n", 50, true, "note.md")]
+    #[case("???\n", 50, true, "note.md")]
+    fn test_first_line(
+        #[case] content: &str,
+        #[case] max_len: usize,
+        #[case] sanitize: bool,
+        #[case] expected: &str,
+    ) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("note.md");
+        fs::write(&path, content).unwrap();
+
+        let result = first_line(&path, rule(max_len, sanitize)).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_first_line_not_text() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("image.png");
+        fs::write(&path, [0x89, b'P', b'N', b'G', 0xff, 0xfe, 0x00, b'\n']).unwrap();
+
+        assert_eq!(first_line(&path, rule(50, true)).unwrap(), "image.png");
+        let missing = dir.path().join("missing.md");
+        assert_eq!(first_line(&missing, rule(50, true)).unwrap(), "missing.md");
+    }
+}
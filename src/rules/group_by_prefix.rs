@@ -0,0 +1,108 @@
+use super::common::split_name;
+use super::error::RuleError;
+use super::number::{NumberRule, number};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GroupByPrefixRule {
+    /// 每组内部使用的编号规则
+    pub number: NumberRule,
+}
+
+/// 文件所属分组的前缀：去掉扩展名后，再去掉末尾的数字和分隔符，
+/// 如 "scanA_12.png" -> "scanA"
+fn group_prefix(name: &str) -> String {
+    let (stem, _) = split_name(name, true);
+    stem.trim_end_matches(|c: char| c.is_ascii_digit() || matches!(c, '_' | '-' | ' ' | '.'))
+        .to_string()
+}
+
+/// 按共同前缀把文件分组，每组独立编号，如扫描件 "scanA_1"、"scanA_2"、"scanB_1"
+/// 在 "scanB" 组重新从起始编号开始。组内保持文件在输入中的相对顺序
+pub fn group_by_prefix(
+    names: &[String],
+    rule: GroupByPrefixRule,
+) -> Result<Vec<String>, RuleError> {
+    // 按前缀首次出现的顺序分组，记录每组文件在原列表中的下标
+    let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+    for (index, name) in names.iter().enumerate() {
+        let prefix = group_prefix(name);
+        match groups.iter_mut().find(|(p, _)| *p == prefix) {
+            Some((_, indices)) => indices.push(index),
+            None => groups.push((prefix, vec![index])),
+        }
+    }
+
+    let mut results = names.to_vec();
+    for (_, indices) in groups {
+        let batch: Vec<String> = indices.iter().map(|&i| names[i].clone()).collect();
+        for (index, renamed) in indices
+            .into_iter()
+            .zip(number(&batch, rule.number.clone())?)
+        {
+            results[index] = renamed;
+        }
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::InsertPosition;
+    use rstest::rstest;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[rstest]
+    #[case("scanA_12.png", "scanA")]
+    #[case("scan B 3.jpg", "scan B")]
+    #[case("notes.txt", "notes")]
+    #[case("2023.txt", "")]
+    fn test_group_prefix(#[case] name: &str, #[case] expected: &str) {
+        assert_eq!(group_prefix(name), expected);
+    }
+
+    #[test]
+    fn test_group_by_prefix_replace_existing() {
+        let rule = GroupByPrefixRule {
+            number: NumberRule {
+                padding: 3,
+                replace_existing: true,
+                ..Default::default()
+            },
+        };
+
+        let input = names(&["scanA_1.png", "scanA_2.png", "scanB_1.png", "scanA_5.png"]);
+        let result = group_by_prefix(&input, rule).unwrap();
+        assert_eq!(
+            result,
+            names(&[
+                "scanA_001.png",
+                "scanA_002.png",
+                "scanB_001.png",
+                "scanA_003.png"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_group_by_prefix_suffix() {
+        let rule = GroupByPrefixRule {
+            number: NumberRule {
+                position: InsertPosition::Suffix,
+                separator: "-".to_string(),
+                ..Default::default()
+            },
+        };
+
+        let input = names(&["page.jpg", "cover.jpg", "page.jpg", "page.jpg"]);
+        let result = group_by_prefix(&input, rule).unwrap();
+        assert_eq!(
+            result,
+            names(&["page-1.jpg", "cover-1.jpg", "page-2.jpg", "page-3.jpg"])
+        );
+    }
+}
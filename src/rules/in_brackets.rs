@@ -0,0 +1,91 @@
+use super::Rule;
+use super::error::RuleError;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InBracketsRule {
+    /// 左括号
+    pub open: char,
+    /// 右括号
+    pub close: char,
+    /// 应用于括号内文本的规则
+    pub inner: Box<Rule>,
+}
+
+/// 只对括号内的文本应用 inner 规则，括号外保持不变。
+/// 嵌套括号按最外层区域整体处理，没有配对的括号不处理
+pub fn in_brackets(old_text: &str, rule: InBracketsRule) -> Result<String, RuleError> {
+    let mut result = String::with_capacity(old_text.len());
+    let mut depth = 0;
+    // 当前最外层区域内文本的起始字节位置
+    let mut region_start = 0;
+
+    for (i, c) in old_text.char_indices() {
+        if depth > 0 && c == rule.close {
+            depth -= 1;
+            if depth == 0 {
+                result.push_str(&rule.inner.apply_one(&old_text[region_start..i])?);
+                result.push(c);
+            }
+        } else if c == rule.open {
+            depth += 1;
+            if depth == 1 {
+                result.push(c);
+                region_start = i + c.len_utf8();
+            }
+        } else if depth == 0 {
+            result.push(c);
+        }
+    }
+    if depth > 0 {
+        // 左括号没有配对，剩余部分原样保留
+        result.push_str(&old_text[region_start..]);
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{CaseKind, CaseRule};
+    use rstest::rstest;
+
+    fn lower() -> Box<Rule> {
+        Box::new(Rule::Case(CaseRule {
+            case: CaseKind::Lower,
+            ignore_extension: false,
+            ..Default::default()
+        }))
+    }
+
+    // 测试样例：只把方括号内的文本转为小写
+    #[rstest]
+    #[case("Movie [DIRTY TAG] Clean.mkv", "Movie [dirty tag] Clean.mkv")]
+    #[case("A [B] C [D].txt", "A [b] C [d].txt")]
+    #[case("Outer [X [Y] Z] W.txt", "Outer [x [y] z] W.txt")]
+    #[case("Unclosed [ABC.txt", "Unclosed [ABC.txt")]
+    #[case("Stray ] CLOSE.txt", "Stray ] CLOSE.txt")]
+    #[case("No Brackets.TXT", "No Brackets.TXT")]
+    fn test_in_brackets_lowercase(#[case] input: &str, #[case] expected: &str) {
+        let rule = InBracketsRule {
+            open: '[',
+            close: ']',
+            inner: lower(),
+        };
+
+        let result = in_brackets(input, rule).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_in_brackets_round() {
+        let rule = InBracketsRule {
+            open: '(',
+            close: ')',
+            inner: lower(),
+        };
+
+        let result = in_brackets("Song (REMIX) [EXPLICIT].mp3", rule).unwrap();
+        assert_eq!(result, "Song (remix) [EXPLICIT].mp3");
+    }
+}
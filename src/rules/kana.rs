@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+
+/// 平假名与片假名在 Unicode 中的码位差
+const KANA_OFFSET: u32 = 0x60;
+
+/// 假名转换的方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KanaDir {
+    /// 平假名转片假名，如 "ひらがな" -> "ヒラガナ"
+    ToKatakana,
+    /// 片假名转平假名
+    ToHiragana,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KanaRule {
+    /// 转换方向
+    pub direction: KanaDir,
+}
+
+fn shift(c: char, offset: i64) -> char {
+    char::from_u32((c as i64 + offset) as u32).unwrap_or(c)
+}
+
+/// 在平假名（U+3041–U+3096、ゝゞ）与片假名（U+30A1–U+30F6、ヽヾ）之间转换，
+/// 汉字、拉丁字母以及没有对应平假名的片假名（如 ヷ、长音符 ー）保持不变
+pub fn kana(old_text: &str, rule: KanaRule) -> String {
+    old_text
+        .chars()
+        .map(|c| match (rule.direction, c) {
+            (KanaDir::ToKatakana, '\u{3041}'..='\u{3096}' | '\u{309D}'..='\u{309E}') => {
+                shift(c, KANA_OFFSET as i64)
+            }
+            (KanaDir::ToHiragana, '\u{30A1}'..='\u{30F6}' | '\u{30FD}'..='\u{30FE}') => {
+                shift(c, -(KANA_OFFSET as i64))
+            }
+            _ => c,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：平假名与片假名互相转换，汉字与拉丁字母不变
+    #[rstest]
+    #[case("ひらがな.txt", KanaDir::ToKatakana, "ヒラガナ.txt")]
+    #[case("ヒラガナ.txt", KanaDir::ToHiragana, "ひらがな.txt")]
+    #[case(
+        "東京 とうきょう EP01.mkv",
+        KanaDir::ToKatakana,
+        "東京 トウキョウ EP01.mkv"
+    )]
+    #[case("ゔぁいおりん", KanaDir::ToKatakana, "ヴァイオリン")]
+    #[case("コーヒー", KanaDir::ToHiragana, "こーひー")]
+    #[case("ヷ", KanaDir::ToHiragana, "ヷ")]
+    fn test_kana(#[case] input: &str, #[case] direction: KanaDir, #[case] expected: &str) {
+        let result = kana(input, KanaRule { direction });
+        assert_eq!(result, expected);
+    }
+}
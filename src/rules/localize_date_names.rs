@@ -0,0 +1,206 @@
+use super::error::RuleError;
+use serde::{Deserialize, Serialize};
+
+/// 内置语言的月份（1-12 月）与星期（周一到周日）名称，顺序一一对应
+const LOCALES: [(&str, [&str; 19]); 4] = [
+    (
+        "en",
+        [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+            "Sunday",
+        ],
+    ),
+    (
+        "de",
+        [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+            "Montag",
+            "Dienstag",
+            "Mittwoch",
+            "Donnerstag",
+            "Freitag",
+            "Samstag",
+            "Sonntag",
+        ],
+    ),
+    (
+        "fr",
+        [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+            "lundi",
+            "mardi",
+            "mercredi",
+            "jeudi",
+            "vendredi",
+            "samedi",
+            "dimanche",
+        ],
+    ),
+    (
+        "es",
+        [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+            "lunes",
+            "martes",
+            "miércoles",
+            "jueves",
+            "viernes",
+            "sábado",
+            "domingo",
+        ],
+    ),
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocalizeDateNamesRule {
+    /// 原语言代码，支持 "en"、"de"、"fr"、"es"
+    pub from_locale: String,
+    /// 目标语言代码
+    pub to_locale: String,
+}
+
+fn names_of(locale: &str) -> Result<&'static [&'static str; 19], RuleError> {
+    LOCALES
+        .iter()
+        .find(|(code, _)| code.eq_ignore_ascii_case(locale))
+        .map(|(_, names)| names)
+        .ok_or_else(|| RuleError::UnknownLocale(locale.to_string()))
+}
+
+/// 按原单词的大小写风格输出译名：全小写、全大写，否则首字母大写
+fn match_case(original: &str, translated: &str) -> String {
+    if !original.chars().any(char::is_uppercase) {
+        translated.to_lowercase()
+    } else if !original.chars().any(char::is_lowercase) {
+        translated.to_uppercase()
+    } else {
+        let mut chars = translated.chars();
+        chars
+            .next()
+            .map(|first| first.to_uppercase().chain(chars).collect())
+            .unwrap_or_default()
+    }
+}
+
+/// 把文件名中完整的月份和星期名称从一种语言翻译为另一种语言（不区分大小写），
+/// 如德语 "Januar" -> 英语 "January"，其余文本保持不变
+pub fn localize_date_names(
+    old_text: &str,
+    rule: LocalizeDateNamesRule,
+) -> Result<String, RuleError> {
+    let from = names_of(&rule.from_locale)?;
+    let to = names_of(&rule.to_locale)?;
+
+    let mut result = String::new();
+    let mut word = String::new();
+    let flush = |word: &mut String, result: &mut String| {
+        let index = from
+            .iter()
+            .position(|name| name.to_lowercase() == word.to_lowercase());
+        match index {
+            Some(index) => result.push_str(&match_case(word, to[index])),
+            None => result.push_str(word),
+        }
+        word.clear();
+    };
+    for c in old_text.chars() {
+        if c.is_alphabetic() {
+            word.push(c);
+        } else {
+            flush(&mut word, &mut result);
+            result.push(c);
+        }
+    }
+    flush(&mut word, &mut result);
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：德语 -> 英语的月份和星期，保留大小写风格
+    #[rstest]
+    #[case("Bericht Januar 2023.pdf", "Bericht January 2023.pdf")]
+    #[case("märz_montag.txt", "march_monday.txt")]
+    #[case("DEZEMBER.txt", "DECEMBER.txt")]
+    #[case("Mainz Maibaum.jpg", "Mainz Maibaum.jpg")]
+    #[case("Mai-Fotos.jpg", "May-Fotos.jpg")]
+    fn test_localize_de_to_en(#[case] input: &str, #[case] expected: &str) {
+        let rule = LocalizeDateNamesRule {
+            from_locale: "de".to_string(),
+            to_locale: "en".to_string(),
+        };
+
+        let result = localize_date_names(input, rule).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_localize_other_locales() {
+        let rule = LocalizeDateNamesRule {
+            from_locale: "EN".to_string(),
+            to_locale: "fr".to_string(),
+        };
+        let result = localize_date_names("February Sunday.txt", rule).unwrap();
+        assert_eq!(result, "Février Dimanche.txt");
+
+        let rule = LocalizeDateNamesRule {
+            from_locale: "de".to_string(),
+            to_locale: "xx".to_string(),
+        };
+        let result = localize_date_names("Januar.txt", rule);
+        assert!(matches!(result, Err(RuleError::UnknownLocale(locale)) if locale == "xx"));
+    }
+}
@@ -0,0 +1,45 @@
+use super::by_category::Category;
+use super::common::split_name;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LowercaseExtensionRule {
+    /// 只处理已知的扩展名（见 `Category`），避免把 "Report.FINAL" 这样
+    /// 以大写单词结尾的主干误当成扩展名
+    pub known_only: bool,
+}
+
+/// 把扩展名改为小写，主干保持不变，如 "Photo.JpG" -> "Photo.jpg"
+pub fn lowercase_extension(old_text: &str, rule: LowercaseExtensionRule) -> String {
+    let (stem, extension) = split_name(old_text, true);
+    if extension.is_empty() || (rule.known_only && Category::of(old_text).is_none()) {
+        return old_text.to_string();
+    }
+    format!("{}{}", stem, extension.to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：大小写混合的扩展名，以及是否只处理已知扩展名
+    #[rstest]
+    #[case("Photo.JpG", false, "Photo.jpg")]
+    #[case("Photo.JpG", true, "Photo.jpg")]
+    #[case("SONG.MP3", true, "SONG.mp3")]
+    #[case("Report.FINAL", false, "Report.final")]
+    #[case("Report.FINAL", true, "Report.FINAL")]
+    #[case("README", false, "README")]
+    #[case(".BASHRC", false, ".BASHRC")]
+    fn test_lowercase_extension(
+        #[case] input: &str,
+        #[case] known_only: bool,
+        #[case] expected: &str,
+    ) {
+        let rule = LowercaseExtensionRule { known_only };
+
+        let result = lowercase_extension(input, rule);
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,110 @@
+use super::error::RuleError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestRule {
+    /// 映射清单，每行为 `旧文件名<Tab>新文件名`，空行会被忽略
+    pub manifest: PathBuf,
+}
+
+/// 读取并校验映射清单，旧文件名或新文件名重复时返回错误
+fn read_manifest(content: &str) -> Result<HashMap<&str, &str>, RuleError> {
+    let mut mapping = HashMap::new();
+    let mut targets: HashMap<&str, usize> = HashMap::new();
+    let mut sources: HashMap<&str, usize> = HashMap::new();
+    for (index, line) in content.lines().enumerate() {
+        let line_no = index + 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let invalid = |reason: String| RuleError::InvalidManifest {
+            line: line_no,
+            reason,
+        };
+
+        let Some((old, new)) = line.split_once('\t') else {
+            return Err(invalid("缺少 Tab 分隔的两列".to_string()));
+        };
+        if old.is_empty() || new.is_empty() || new.contains('\t') {
+            return Err(invalid("应为 旧文件名<Tab>新文件名".to_string()));
+        }
+        if let Some(first) = sources.insert(old, line_no) {
+            return Err(invalid(format!("旧文件名 {} 与第 {} 行重复", old, first)));
+        }
+        if let Some(first) = targets.insert(new, line_no) {
+            return Err(invalid(format!("新文件名 {} 与第 {} 行重复", new, first)));
+        }
+        mapping.insert(old, new);
+    }
+    Ok(mapping)
+}
+
+/// 按映射清单把文件名精确替换为指定的新文件名，清单中没有的文件保持原样
+pub fn manifest(names: &[String], rule: ManifestRule) -> Result<Vec<String>, RuleError> {
+    let content = fs::read_to_string(&rule.manifest)?;
+    let mapping = read_manifest(&content)?;
+
+    Ok(names
+        .iter()
+        .map(|name| match mapping.get(name.as_str()) {
+            Some(new) => new.to_string(),
+            None => name.clone(),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn run(content: &str, input: &[&str]) -> Result<Vec<String>, RuleError> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("rename.tsv");
+        fs::write(&path, content).unwrap();
+        manifest(&names(input), ManifestRule { manifest: path })
+    }
+
+    #[test]
+    fn test_manifest() {
+        let content = "IMG_001.jpg\tbeach.jpg\nIMG_002.jpg\tsunset.jpg\n\nnotes.txt\tREADME.md\n";
+
+        let result = run(
+            content,
+            &["IMG_002.jpg", "IMG_003.jpg", "notes.txt", "IMG_001.jpg"],
+        );
+        assert_eq!(
+            result.unwrap(),
+            names(&["sunset.jpg", "IMG_003.jpg", "README.md", "beach.jpg"])
+        );
+    }
+
+    // 测试样例：格式错误或重复的行报告行号
+    #[rstest]
+    #[case("a.txt\tb.txt\nc.txt d.txt\n", 2)]
+    #[case("a.txt\tb.txt\nc.txt\t\n", 2)]
+    #[case("a.txt\tb.txt\n\na.txt\tc.txt\n", 3)]
+    #[case("a.txt\tb.txt\nc.txt\tb.txt\n", 2)]
+    fn test_manifest_invalid(#[case] content: &str, #[case] line: usize) {
+        let result = run(content, &["a.txt"]);
+        assert!(matches!(result, Err(RuleError::InvalidManifest { line: l, .. }) if l == line));
+    }
+
+    #[test]
+    fn test_manifest_missing() {
+        let dir = tempfile::tempdir().unwrap();
+        let rule = ManifestRule {
+            manifest: dir.path().join("missing.tsv"),
+        };
+
+        let result = manifest(&names(&["a.txt"]), rule);
+        assert!(matches!(result, Err(RuleError::Io(_))));
+    }
+}
@@ -0,0 +1,115 @@
+use super::Rule;
+use super::error::RuleError;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::time::SystemTime;
+
+/// 基于文件元数据的条件
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MetaCondition {
+    /// 文件大小（字节）大于给定值
+    LargerThan(u64),
+    /// 文件大小（字节）小于给定值
+    SmallerThan(u64),
+    /// 修改时间早于给定时间
+    OlderThan(SystemTime),
+    /// 修改时间晚于给定时间
+    NewerThan(SystemTime),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetaConditionRule {
+    /// 判断条件
+    pub condition: MetaCondition,
+    /// 条件成立时应用于文件名的规则
+    pub inner: Box<Rule>,
+}
+
+impl MetaCondition {
+    fn holds(self, metadata: &fs::Metadata) -> Result<bool, RuleError> {
+        Ok(match self {
+            MetaCondition::LargerThan(size) => metadata.len() > size,
+            MetaCondition::SmallerThan(size) => metadata.len() < size,
+            MetaCondition::OlderThan(time) => metadata.modified()? < time,
+            MetaCondition::NewerThan(time) => metadata.modified()? > time,
+        })
+    }
+}
+
+/// 读取文件元数据，条件成立时对文件名应用 inner 规则，否则保持原文件名
+pub fn meta_condition(path: &Path, rule: MetaConditionRule) -> Result<String, RuleError> {
+    let old_text = path
+        .file_name()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    if rule.condition.holds(&fs::metadata(path)?)? {
+        rule.inner.apply_one(&old_text)
+    } else {
+        Ok(old_text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{InsertPosition, VariantSuffixRule};
+    use rstest::rstest;
+    use std::time::Duration;
+
+    fn tag_large() -> Box<Rule> {
+        Box::new(Rule::VariantSuffix(VariantSuffixRule {
+            suffix: "_large".to_string(),
+            position: InsertPosition::Suffix,
+        }))
+    }
+
+    // 测试样例：按大小阈值决定是否应用规则
+    #[rstest]
+    #[case(MetaCondition::LargerThan(1000), "big_large.bin", "small.bin")]
+    #[case(MetaCondition::LargerThan(10), "big_large.bin", "small_large.bin")]
+    #[case(MetaCondition::SmallerThan(1000), "big.bin", "small_large.bin")]
+    fn test_meta_condition_size(
+        #[case] condition: MetaCondition,
+        #[case] expected_big: &str,
+        #[case] expected_small: &str,
+    ) {
+        let dir = tempfile::tempdir().unwrap();
+        let big = dir.path().join("big.bin");
+        let small = dir.path().join("small.bin");
+        fs::write(&big, vec![0u8; 2048]).unwrap();
+        fs::write(&small, vec![0u8; 100]).unwrap();
+
+        let rule = MetaConditionRule {
+            condition,
+            inner: tag_large(),
+        };
+
+        assert_eq!(meta_condition(&big, rule.clone()).unwrap(), expected_big);
+        assert_eq!(meta_condition(&small, rule).unwrap(), expected_small);
+    }
+
+    #[test]
+    fn test_meta_condition_time() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("file.txt");
+        fs::write(&path, "hello").unwrap();
+        let hour_ago = SystemTime::now() - Duration::from_secs(3600);
+
+        let rule = |condition| MetaConditionRule {
+            condition,
+            inner: tag_large(),
+        };
+        let newer = meta_condition(&path, rule(MetaCondition::NewerThan(hour_ago))).unwrap();
+        assert_eq!(newer, "file_large.txt");
+        let older = meta_condition(&path, rule(MetaCondition::OlderThan(hour_ago))).unwrap();
+        assert_eq!(older, "file.txt");
+
+        let missing = meta_condition(
+            &dir.path().join("missing.txt"),
+            rule(MetaCondition::LargerThan(0)),
+        );
+        assert!(matches!(missing, Err(RuleError::Io(_))));
+    }
+}
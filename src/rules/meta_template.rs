@@ -0,0 +1,137 @@
+use super::common::{format_size, render_template};
+use super::date_reformat::format_datetime;
+use super::error::RuleError;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetaTemplateRule {
+    /// 新文件名模板，支持以下占位符（`{{` 和 `}}` 表示字面的花括号）：
+    /// - `{name}`: 原文件名去掉扩展名的部分
+    /// - `{ext}`: 原扩展名（不含点）
+    /// - `{parent}`: 所在目录名
+    /// - `{size}`: 文件大小（字节数）；`{size:auto}` 为可读形式，如 1.5KiB
+    /// - `{mtime}`: 修改时间（默认 %Y-%m-%d）；`{mtime:<格式>}` 使用 chrono strftime 格式
+    pub pattern: String,
+}
+
+fn render_token(path: &Path, token: &str) -> Result<String, RuleError> {
+    let (key, arg) = match token.split_once(':') {
+        Some((key, arg)) => (key, Some(arg)),
+        None => (token, None),
+    };
+    let lossy = |s: Option<&std::ffi::OsStr>| {
+        s.map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    };
+
+    match (key, arg) {
+        ("name", None) => Ok(lossy(path.file_stem())),
+        ("ext", None) => Ok(lossy(path.extension())),
+        ("parent", None) => Ok(lossy(path.parent().and_then(Path::file_name))),
+        ("size", None) => Ok(fs::metadata(path)?.len().to_string()),
+        ("size", Some("auto")) => Ok(format_size(fs::metadata(path)?.len())),
+        ("mtime", format) => {
+            let modified: DateTime<Local> = fs::metadata(path)?.modified()?.into();
+            format_datetime(modified.naive_local(), format.unwrap_or("%Y-%m-%d"))
+                .ok_or_else(|| RuleError::UnknownToken(token.to_string()))
+        }
+        _ => Err(RuleError::UnknownToken(token.to_string())),
+    }
+}
+
+/// 用路径、大小和修改时间等元数据渲染模板，结果作为新的完整文件名
+pub fn meta_template(path: &Path, rule: MetaTemplateRule) -> Result<String, RuleError> {
+    render_template(&rule.pattern, |token| render_token(path, token))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rstest::rstest;
+    use std::fs::File;
+    use std::time::SystemTime;
+
+    fn fixture(dir: &Path) -> std::path::PathBuf {
+        let folder = dir.join("holiday");
+        fs::create_dir(&folder).unwrap();
+        let path = folder.join("beach.jpg");
+        fs::write(&path, vec![0u8; 1536]).unwrap();
+
+        let mtime: SystemTime = Local.with_ymd_and_hms(2023, 5, 1, 12, 0, 0).unwrap().into();
+        File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(mtime)
+            .unwrap();
+        path
+    }
+
+    // 测试样例：组合多个元数据字段
+    #[rstest]
+    #[case("{mtime:%Y-%m-%d}_{name}.{ext}", "2023-05-01_beach.jpg")]
+    #[case("{mtime}", "2023-05-01")]
+    #[case("{mtime:%Y%m%d_%H%M}", "20230501_1200")]
+    #[case("{parent}-{name} ({size:auto}).{ext}", "holiday-beach (1.5KiB).jpg")]
+    #[case("{name}_{size}", "beach_1536")]
+    #[case("{{{name}}}", "{beach}")]
+    fn test_meta_template(#[case] pattern: &str, #[case] expected: &str) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = fixture(dir.path());
+
+        let rule = MetaTemplateRule {
+            pattern: pattern.to_string(),
+        };
+
+        let result = meta_template(&path, rule).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    // 测试样例：未知占位符返回错误
+    #[rstest]
+    #[case("{owner}", "owner")]
+    #[case("{size:kb}", "size:kb")]
+    #[case("{name:upper}", "name:upper")]
+    fn test_meta_template_unknown_token(#[case] pattern: &str, #[case] token: &str) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = fixture(dir.path());
+
+        let rule = MetaTemplateRule {
+            pattern: pattern.to_string(),
+        };
+
+        let result = meta_template(&path, rule);
+        assert!(matches!(result, Err(RuleError::UnknownToken(t)) if t == token));
+    }
+
+    // 测试样例：占位符缺少结尾的 "}"
+    #[rstest]
+    #[case("{name")]
+    #[case("{name}_{mtime:%Y")]
+    fn test_meta_template_unterminated_token(#[case] pattern: &str) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = fixture(dir.path());
+
+        let rule = MetaTemplateRule {
+            pattern: pattern.to_string(),
+        };
+
+        let result = meta_template(&path, rule);
+        assert!(matches!(result, Err(RuleError::UnterminatedToken(t)) if t == pattern));
+    }
+
+    #[test]
+    fn test_meta_template_missing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let rule = MetaTemplateRule {
+            pattern: "{size}".to_string(),
+        };
+
+        let result = meta_template(&dir.path().join("missing.txt"), rule);
+        assert!(matches!(result, Err(RuleError::Io(_))));
+    }
+}
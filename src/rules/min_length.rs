@@ -0,0 +1,86 @@
+use super::common::split_name;
+use super::error::RuleError;
+use serde::{Deserialize, Serialize};
+
+/// 填充方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PadKind {
+    /// 按出现顺序填充递增的计数器（从 1 开始，左侧补零），使填充后的文件名互不相同
+    Counter,
+    /// 填充 '0'
+    Zeros,
+    /// 填充重复的指定字符
+    Char(char),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MinLengthRule {
+    /// 去掉扩展名后的最小字符数
+    pub min_len: usize,
+    /// 填充方式
+    pub pad_with: PadKind,
+}
+
+/// 为去掉扩展名后短于 min_len 个字符的文件名在末尾补足长度，较长的文件名保持原样。
+/// 计数器的位数超过需要补足的长度时保留完整的计数器
+pub fn min_length(names: &[String], rule: MinLengthRule) -> Result<Vec<String>, RuleError> {
+    let mut counter = 0;
+    Ok(names
+        .iter()
+        .map(|name| {
+            let (name_to_process, extension_to_append) = split_name(name, true);
+            let len = name_to_process.chars().count();
+            if len >= rule.min_len {
+                return name.clone();
+            }
+            let missing = rule.min_len - len;
+            let padding = match rule.pad_with {
+                PadKind::Counter => {
+                    counter += 1;
+                    format!("{:0width$}", counter, width = missing)
+                }
+                PadKind::Zeros => "0".repeat(missing),
+                PadKind::Char(c) => c.to_string().repeat(missing),
+            };
+            format!("{}{}{}", name_to_process, padding, extension_to_append)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    // 测试样例：2 个字符的文件名补足到 5 个字符
+    #[rstest]
+    #[case(PadKind::Counter, &["ab001.txt", "longname.txt", "cd002.txt"])]
+    #[case(PadKind::Zeros, &["ab000.txt", "longname.txt", "cd000.txt"])]
+    #[case(PadKind::Char('x'), &["abxxx.txt", "longname.txt", "cdxxx.txt"])]
+    fn test_min_length(#[case] pad_with: PadKind, #[case] expected: &[&str]) {
+        let rule = MinLengthRule {
+            min_len: 5,
+            pad_with,
+        };
+
+        let result = min_length(&names(&["ab.txt", "longname.txt", "cd.txt"]), rule).unwrap();
+        assert_eq!(result, names(expected));
+    }
+
+    #[test]
+    fn test_min_length_counter_overflow() {
+        let input: Vec<String> = (0..12).map(|_| "abcd".to_string()).collect();
+        let rule = MinLengthRule {
+            min_len: 5,
+            pad_with: PadKind::Counter,
+        };
+
+        let result = min_length(&input, rule).unwrap();
+        assert_eq!(result[0], "abcd1");
+        assert_eq!(result[11], "abcd12");
+    }
+}
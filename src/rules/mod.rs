@@ -1,10 +1,555 @@
+mod ambiguous_date;
+mod archive_tag;
+mod by_category;
+mod caesar;
+mod camel_split;
+mod case;
+mod cloud_sanitize;
+mod column;
+mod common;
+mod context;
+mod context_template;
+mod date_prefix_normalize;
+mod date_reformat;
+mod decimal_align;
+mod dedupe_words;
+#[cfg(feature = "magic")]
+mod detect_extension;
+mod digit_group;
+#[cfg(feature = "image")]
+mod dimensions;
+#[cfg(feature = "audio")]
+mod duration;
+mod ensure_number;
+mod error;
+#[cfg(feature = "exif")]
+mod exif_tag;
+mod expand;
+mod expr;
+mod extension_swap;
+mod first_line;
+mod group_by_prefix;
+mod in_brackets;
+mod kana;
+mod localize_date_names;
+mod lowercase_extension;
+mod manifest;
+mod meta_condition;
+mod meta_template;
+mod min_length;
+mod noise_words;
+mod number;
+mod number_grouping;
+mod number_matches;
+mod number_words;
+mod order_file;
+mod ordinal;
+mod path_collapse;
+mod pipeline;
+mod punctuation_normalize;
+mod regex_replace;
+mod regex_table;
+mod relative_number;
 mod remove;
+mod remove_leading_number;
+mod remove_release_group;
+mod repair_brackets;
+#[cfg(feature = "romanize")]
+mod romanize;
+mod short_id;
+mod shorten;
+mod sidecar;
+mod size_format;
+mod slugify;
+mod smart_reorder;
+mod sort_key;
+mod spacing;
+mod strip_brackets;
+mod strip_control;
+mod strip_invisible;
+mod strip_query;
+mod text_stats;
+mod tidy;
+mod time_format;
+#[cfg(feature = "tz")]
+mod timezone_shift;
+mod transliterate;
+mod truncate;
+mod tv_cleanup;
+mod unicode_form;
+mod unify_extension_case;
+mod unify_separators;
+mod unique;
+mod variant_suffix;
+mod version_pad;
+mod whitelist;
+mod window;
+mod word_limit;
 
 use serde::{Deserialize, Serialize};
 
+pub use ambiguous_date::*;
+pub use archive_tag::*;
+pub use by_category::*;
+pub use caesar::*;
+pub use camel_split::*;
+pub use case::*;
+pub use cloud_sanitize::*;
+pub use column::*;
+pub use common::*;
+pub use context::*;
+pub use context_template::*;
+pub use date_prefix_normalize::*;
+pub use date_reformat::*;
+pub use decimal_align::*;
+pub use dedupe_words::*;
+#[cfg(feature = "magic")]
+pub use detect_extension::*;
+pub use digit_group::*;
+#[cfg(feature = "image")]
+pub use dimensions::*;
+#[cfg(feature = "audio")]
+pub use duration::*;
+pub use ensure_number::*;
+pub use error::*;
+#[cfg(feature = "exif")]
+pub use exif_tag::*;
+pub use expand::*;
+pub use expr::*;
+pub use extension_swap::*;
+pub use first_line::*;
+pub use group_by_prefix::*;
+pub use in_brackets::*;
+pub use kana::*;
+pub use localize_date_names::*;
+pub use lowercase_extension::*;
+pub use manifest::*;
+pub use meta_condition::*;
+pub use meta_template::*;
+pub use min_length::*;
+pub use noise_words::*;
+pub use number::*;
+pub use number_grouping::*;
+pub use number_matches::*;
+pub use number_words::*;
+pub use order_file::*;
+pub use ordinal::*;
+pub use path_collapse::*;
+pub use pipeline::*;
+pub use punctuation_normalize::*;
+pub use regex_replace::*;
+pub use regex_table::*;
+pub use relative_number::*;
 pub use remove::*;
+pub use remove_leading_number::*;
+pub use remove_release_group::*;
+pub use repair_brackets::*;
+#[cfg(feature = "romanize")]
+pub use romanize::*;
+pub use short_id::*;
+pub use shorten::*;
+pub use sidecar::*;
+pub use size_format::*;
+pub use slugify::*;
+pub use smart_reorder::*;
+pub use sort_key::*;
+pub use spacing::*;
+pub use strip_brackets::*;
+pub use strip_control::*;
+pub use strip_invisible::*;
+pub use strip_query::*;
+pub use text_stats::*;
+pub use tidy::*;
+pub use time_format::*;
+#[cfg(feature = "tz")]
+pub use timezone_shift::*;
+pub use transliterate::*;
+pub use truncate::*;
+pub use tv_cleanup::*;
+pub use unicode_form::*;
+pub use unify_extension_case::*;
+pub use unify_separators::*;
+pub use unique::*;
+pub use variant_suffix::*;
+pub use version_pad::*;
+pub use whitelist::*;
+pub use window::*;
+pub use word_limit::*;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RuleType {
     Remove,
+    Number,
+    NoiseWords,
+    DateReformat,
+    WordLimit,
+    VersionPad,
+    EnsureNumber,
+    PunctuationNormalize,
+    ExtensionSwap,
+    Shorten,
+    NumberWords,
+    Case,
+    InBrackets,
+    AmbiguousDate,
+    RemoveLeadingNumber,
+    Spacing,
+    NumberGrouping,
+    Caesar,
+    UnicodeForm,
+    Truncate,
+    TruncateWord,
+    RegexTable,
+    RegexReplace,
+    Sed,
+    NumberMatches,
+    StripBrackets,
+    OrderFile,
+    DedupeWords,
+    Kana,
+    Manifest,
+    DecimalAlign,
+    Ordinal,
+    StripInvisible,
+    Slugify,
+    RelativeNumber,
+    CamelSplit,
+    UnifyExtensionCase,
+    Window,
+    Expand,
+    VariantSuffix,
+    DatePrefixNormalize,
+    PathCollapse,
+    ShortId,
+    DigitGroup,
+    ByCategory,
+    Whitelist,
+    MinLength,
+    GroupByPrefix,
+    RemoveReleaseGroup,
+    TimeFormat,
+    SizeFormat,
+    Column,
+    Transliterate,
+    LocalizeDateNames,
+    StripQuery,
+    CloudSanitize,
+    Tidy,
+    LowercaseExtension,
+    StripControl,
+    SmartReorder,
+    Expr,
+    TvCleanup,
+    RepairBrackets,
+}
+
+/// 携带具体参数的规则，用于组成规则链
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Rule {
+    Remove(RemoveRule),
+    Number(NumberRule),
+    NoiseWords(NoiseWordsRule),
+    DateReformat(DateReformatRule),
+    WordLimit(WordLimitRule),
+    VersionPad(VersionPadRule),
+    EnsureNumber(EnsureNumberRule),
+    PunctuationNormalize(PunctuationNormalizeRule),
+    ExtensionSwap(ExtensionSwapRule),
+    Shorten(ShortenRule),
+    NumberWords(NumberWordsRule),
+    Case(CaseRule),
+    InBrackets(InBracketsRule),
+    AmbiguousDate(AmbiguousDateRule),
+    RemoveLeadingNumber(RemoveLeadingNumberRule),
+    Spacing(SpacingRule),
+    NumberGrouping(NumberGroupingRule),
+    Caesar(CaesarRule),
+    UnicodeForm(UnicodeFormRule),
+    Truncate(TruncateRule),
+    TruncateWord(TruncateWordRule),
+    RegexTable(RegexTableRule),
+    RegexReplace(RegexReplaceRule),
+    Sed(SedRule),
+    NumberMatches(NumberMatchesRule),
+    StripBrackets(StripBracketsRule),
+    OrderFile(OrderFileRule),
+    DedupeWords(DedupeWordsRule),
+    Kana(KanaRule),
+    Manifest(ManifestRule),
+    DecimalAlign(DecimalAlignRule),
+    Ordinal(OrdinalRule),
+    StripInvisible(StripInvisibleRule),
+    Slugify(SlugifyRule),
+    RelativeNumber(RelativeNumberRule),
+    CamelSplit(CamelSplitRule),
+    UnifyExtensionCase(UnifyExtensionCaseRule),
+    Window(WindowRule),
+    Expand(ExpandRule),
+    VariantSuffix(VariantSuffixRule),
+    DatePrefixNormalize(DatePrefixNormalizeRule),
+    PathCollapse(PathCollapseRule),
+    ShortId(ShortIdRule),
+    DigitGroup(DigitGroupRule),
+    ByCategory(ByCategoryRule),
+    Whitelist(WhitelistRule),
+    MinLength(MinLengthRule),
+    GroupByPrefix(GroupByPrefixRule),
+    RemoveReleaseGroup(RemoveReleaseGroupRule),
+    TimeFormat(TimeFormatRule),
+    SizeFormat(SizeFormatRule),
+    Column(ColumnRule),
+    Transliterate(TransliterateRule),
+    LocalizeDateNames(LocalizeDateNamesRule),
+    StripQuery(StripQueryRule),
+    CloudSanitize(CloudSanitizeRule),
+    Tidy(TidyRule),
+    LowercaseExtension(LowercaseExtensionRule),
+    StripControl(StripControlRule),
+    SmartReorder(SmartReorderRule),
+    Expr(ExprRule),
+    TvCleanup(TvCleanupRule),
+    RepairBrackets(RepairBracketsRule),
+}
+
+impl Rule {
+    pub fn rule_type(&self) -> RuleType {
+        match self {
+            Rule::Remove(_) => RuleType::Remove,
+            Rule::Number(_) => RuleType::Number,
+            Rule::NoiseWords(_) => RuleType::NoiseWords,
+            Rule::DateReformat(_) => RuleType::DateReformat,
+            Rule::WordLimit(_) => RuleType::WordLimit,
+            Rule::VersionPad(_) => RuleType::VersionPad,
+            Rule::EnsureNumber(_) => RuleType::EnsureNumber,
+            Rule::PunctuationNormalize(_) => RuleType::PunctuationNormalize,
+            Rule::ExtensionSwap(_) => RuleType::ExtensionSwap,
+            Rule::Shorten(_) => RuleType::Shorten,
+            Rule::NumberWords(_) => RuleType::NumberWords,
+            Rule::Case(_) => RuleType::Case,
+            Rule::InBrackets(_) => RuleType::InBrackets,
+            Rule::AmbiguousDate(_) => RuleType::AmbiguousDate,
+            Rule::RemoveLeadingNumber(_) => RuleType::RemoveLeadingNumber,
+            Rule::Spacing(_) => RuleType::Spacing,
+            Rule::NumberGrouping(_) => RuleType::NumberGrouping,
+            Rule::Caesar(_) => RuleType::Caesar,
+            Rule::UnicodeForm(_) => RuleType::UnicodeForm,
+            Rule::Truncate(_) => RuleType::Truncate,
+            Rule::TruncateWord(_) => RuleType::TruncateWord,
+            Rule::RegexTable(_) => RuleType::RegexTable,
+            Rule::RegexReplace(_) => RuleType::RegexReplace,
+            Rule::Sed(_) => RuleType::Sed,
+            Rule::NumberMatches(_) => RuleType::NumberMatches,
+            Rule::StripBrackets(_) => RuleType::StripBrackets,
+            Rule::OrderFile(_) => RuleType::OrderFile,
+            Rule::DedupeWords(_) => RuleType::DedupeWords,
+            Rule::Kana(_) => RuleType::Kana,
+            Rule::Manifest(_) => RuleType::Manifest,
+            Rule::DecimalAlign(_) => RuleType::DecimalAlign,
+            Rule::Ordinal(_) => RuleType::Ordinal,
+            Rule::StripInvisible(_) => RuleType::StripInvisible,
+            Rule::Slugify(_) => RuleType::Slugify,
+            Rule::RelativeNumber(_) => RuleType::RelativeNumber,
+            Rule::CamelSplit(_) => RuleType::CamelSplit,
+            Rule::UnifyExtensionCase(_) => RuleType::UnifyExtensionCase,
+            Rule::Window(_) => RuleType::Window,
+            Rule::Expand(_) => RuleType::Expand,
+            Rule::VariantSuffix(_) => RuleType::VariantSuffix,
+            Rule::DatePrefixNormalize(_) => RuleType::DatePrefixNormalize,
+            Rule::PathCollapse(_) => RuleType::PathCollapse,
+            Rule::ShortId(_) => RuleType::ShortId,
+            Rule::DigitGroup(_) => RuleType::DigitGroup,
+            Rule::ByCategory(_) => RuleType::ByCategory,
+            Rule::Whitelist(_) => RuleType::Whitelist,
+            Rule::MinLength(_) => RuleType::MinLength,
+            Rule::GroupByPrefix(_) => RuleType::GroupByPrefix,
+            Rule::RemoveReleaseGroup(_) => RuleType::RemoveReleaseGroup,
+            Rule::TimeFormat(_) => RuleType::TimeFormat,
+            Rule::SizeFormat(_) => RuleType::SizeFormat,
+            Rule::Column(_) => RuleType::Column,
+            Rule::Transliterate(_) => RuleType::Transliterate,
+            Rule::LocalizeDateNames(_) => RuleType::LocalizeDateNames,
+            Rule::StripQuery(_) => RuleType::StripQuery,
+            Rule::CloudSanitize(_) => RuleType::CloudSanitize,
+            Rule::Tidy(_) => RuleType::Tidy,
+            Rule::LowercaseExtension(_) => RuleType::LowercaseExtension,
+            Rule::StripControl(_) => RuleType::StripControl,
+            Rule::SmartReorder(_) => RuleType::SmartReorder,
+            Rule::Expr(_) => RuleType::Expr,
+            Rule::TvCleanup(_) => RuleType::TvCleanup,
+            Rule::RepairBrackets(_) => RuleType::RepairBrackets,
+        }
+    }
+
+    /// 对一批文件名应用当前规则，返回结果与输入一一对应
+    pub fn apply(&self, names: &[String]) -> Result<Vec<String>, RuleError> {
+        match self {
+            Rule::Remove(rule) => Ok(names.iter().map(|n| remove(n, rule.clone())).collect()),
+            Rule::Number(rule) => number(names, rule.clone()),
+            Rule::NoiseWords(rule) => {
+                Ok(names.iter().map(|n| noise_words(n, rule.clone())).collect())
+            }
+            Rule::DateReformat(rule) => Ok(names
+                .iter()
+                .map(|n| date_reformat(n, rule.clone()))
+                .collect()),
+            Rule::WordLimit(rule) => {
+                Ok(names.iter().map(|n| word_limit(n, rule.clone())).collect())
+            }
+            Rule::VersionPad(rule) => {
+                Ok(names.iter().map(|n| version_pad(n, rule.clone())).collect())
+            }
+            Rule::EnsureNumber(rule) => ensure_number(names, rule.clone()),
+            Rule::PunctuationNormalize(rule) => Ok(names
+                .iter()
+                .map(|n| punctuation_normalize(n, rule.clone()))
+                .collect()),
+            Rule::ExtensionSwap(rule) => Ok(names
+                .iter()
+                .map(|n| extension_swap(n, rule.clone()))
+                .collect()),
+            Rule::Shorten(rule) => Ok(names.iter().map(|n| shorten(n, rule.clone())).collect()),
+            Rule::NumberWords(rule) => Ok(names
+                .iter()
+                .map(|n| number_words(n, rule.clone()))
+                .collect()),
+            Rule::Case(rule) => Ok(names.iter().map(|n| case(n, rule.clone())).collect()),
+            Rule::InBrackets(rule) => names.iter().map(|n| in_brackets(n, rule.clone())).collect(),
+            Rule::AmbiguousDate(rule) => Ok(names
+                .iter()
+                .map(|n| ambiguous_date(n, rule.clone()))
+                .collect()),
+            Rule::RemoveLeadingNumber(rule) => Ok(names
+                .iter()
+                .map(|n| remove_leading_number(n, rule.clone()))
+                .collect()),
+            Rule::Spacing(rule) => Ok(names.iter().map(|n| spacing(n, rule.clone())).collect()),
+            Rule::NumberGrouping(rule) => Ok(names
+                .iter()
+                .map(|n| number_grouping(n, rule.clone()))
+                .collect()),
+            Rule::Caesar(rule) => Ok(names.iter().map(|n| caesar(n, rule.clone())).collect()),
+            Rule::UnicodeForm(rule) => Ok(names
+                .iter()
+                .map(|n| unicode_form(n, rule.clone()))
+                .collect()),
+            Rule::Truncate(rule) => Ok(names.iter().map(|n| truncate(n, rule.clone())).collect()),
+            Rule::TruncateWord(rule) => Ok(names
+                .iter()
+                .map(|n| truncate_word(n, rule.clone()))
+                .collect()),
+            Rule::RegexTable(rule) => names.iter().map(|n| regex_table(n, rule.clone())).collect(),
+            Rule::RegexReplace(rule) => names
+                .iter()
+                .map(|n| regex_replace(n, rule.clone()))
+                .collect(),
+            Rule::Sed(rule) => names.iter().map(|n| sed(n, rule.clone())).collect(),
+            Rule::NumberMatches(rule) => number_matches(names, rule.clone()),
+            Rule::StripBrackets(rule) => Ok(names
+                .iter()
+                .map(|n| strip_brackets(n, rule.clone()))
+                .collect()),
+            Rule::OrderFile(rule) => order_file(names, rule.clone()),
+            Rule::DedupeWords(rule) => Ok(names
+                .iter()
+                .map(|n| dedupe_words(n, rule.clone()))
+                .collect()),
+            Rule::Kana(rule) => Ok(names.iter().map(|n| kana(n, rule.clone())).collect()),
+            Rule::Manifest(rule) => manifest(names, rule.clone()),
+            Rule::DecimalAlign(rule) => Ok(names
+                .iter()
+                .map(|n| decimal_align(n, rule.clone()))
+                .collect()),
+            Rule::Ordinal(rule) => Ok(names.iter().map(|n| ordinal(n, rule.clone())).collect()),
+            Rule::StripInvisible(rule) => Ok(names
+                .iter()
+                .map(|n| strip_invisible(n, rule.clone()))
+                .collect()),
+            Rule::Slugify(rule) => Ok(names.iter().map(|n| slugify(n, rule.clone())).collect()),
+            Rule::RelativeNumber(rule) => relative_number(names, rule.clone()),
+            Rule::CamelSplit(rule) => {
+                Ok(names.iter().map(|n| camel_split(n, rule.clone())).collect())
+            }
+            Rule::UnifyExtensionCase(rule) => unify_extension_case(names, rule.clone()),
+            Rule::Window(rule) => names.iter().map(|n| window(n, rule.clone())).collect(),
+            Rule::Expand(rule) => Ok(names.iter().map(|n| expand(n, rule.clone())).collect()),
+            Rule::VariantSuffix(rule) => Ok(names
+                .iter()
+                .map(|n| variant_suffix(n, rule.clone()))
+                .collect()),
+            Rule::DatePrefixNormalize(rule) => Ok(names
+                .iter()
+                .map(|n| date_prefix_normalize(n, rule.clone()))
+                .collect()),
+            Rule::PathCollapse(rule) => Ok(names
+                .iter()
+                .map(|n| path_collapse(n, rule.clone()))
+                .collect()),
+            Rule::ShortId(rule) => short_id(names, rule.clone()),
+            Rule::DigitGroup(rule) => {
+                Ok(names.iter().map(|n| digit_group(n, rule.clone())).collect())
+            }
+            Rule::ByCategory(rule) => by_category(names, rule.clone()),
+            Rule::Whitelist(rule) => Ok(names.iter().map(|n| whitelist(n, rule.clone())).collect()),
+            Rule::MinLength(rule) => min_length(names, rule.clone()),
+            Rule::GroupByPrefix(rule) => group_by_prefix(names, rule.clone()),
+            Rule::RemoveReleaseGroup(rule) => Ok(names
+                .iter()
+                .map(|n| remove_release_group(n, rule.clone()))
+                .collect()),
+            Rule::TimeFormat(rule) => {
+                Ok(names.iter().map(|n| time_format(n, rule.clone())).collect())
+            }
+            Rule::SizeFormat(rule) => {
+                Ok(names.iter().map(|n| size_format(n, rule.clone())).collect())
+            }
+            Rule::Column(rule) => Ok(names.iter().map(|n| column(n, rule.clone())).collect()),
+            Rule::Transliterate(rule) => Ok(names
+                .iter()
+                .map(|n| transliterate(n, rule.clone()))
+                .collect()),
+            Rule::LocalizeDateNames(rule) => names
+                .iter()
+                .map(|n| localize_date_names(n, rule.clone()))
+                .collect(),
+            Rule::StripQuery(rule) => {
+                Ok(names.iter().map(|n| strip_query(n, rule.clone())).collect())
+            }
+            Rule::CloudSanitize(rule) => Ok(names
+                .iter()
+                .map(|n| cloud_sanitize(n, rule.clone()))
+                .collect()),
+            Rule::Tidy(rule) => Ok(names.iter().map(|n| tidy(n, rule.clone())).collect()),
+            Rule::LowercaseExtension(rule) => Ok(names
+                .iter()
+                .map(|n| lowercase_extension(n, rule.clone()))
+                .collect()),
+            Rule::StripControl(rule) => Ok(names
+                .iter()
+                .map(|n| strip_control(n, rule.clone()))
+                .collect()),
+            Rule::SmartReorder(rule) => Ok(names
+                .iter()
+                .map(|n| smart_reorder(n, rule.clone()))
+                .collect()),
+            Rule::Expr(rule) => expr(names, rule.clone()),
+            Rule::TvCleanup(rule) => names.iter().map(|n| tv_cleanup(n, rule.clone())).collect(),
+            Rule::RepairBrackets(rule) => Ok(names
+                .iter()
+                .map(|n| repair_brackets(n, rule.clone()))
+                .collect()),
+        }
+    }
+
+    /// 对单个文件名应用当前规则
+    pub fn apply_one(&self, name: &str) -> Result<String, RuleError> {
+        let mut results = self.apply(&[name.to_string()])?;
+        Ok(results.pop().unwrap_or_default())
+    }
+}
+
+/// 按顺序对一批文件名应用整条规则链
+pub fn apply_rules(names: &[String], rules: &[Rule]) -> Result<Vec<String>, RuleError> {
+    rules
+        .iter()
+        .try_fold(names.to_vec(), |current, rule| rule.apply(&current))
 }
@@ -0,0 +1,129 @@
+use super::common::split_name;
+use regex::RegexBuilder;
+use serde::{Deserialize, Serialize};
+
+/// 清理时视为分隔符的字符
+const SEPARATORS: [char; 4] = [' ', '.', '_', '-'];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NoiseWordsRule {
+    /// 要移除的噪音词
+    pub words: Vec<String>,
+    /// 区分大小写
+    pub case_sensitive: bool,
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+}
+
+impl NoiseWordsRule {
+    /// 影视资源文件名中常见的分辨率、片源、编码等标记
+    pub fn video_defaults() -> Self {
+        let words = [
+            "2160p", "1080p", "720p", "480p", "4K", "UHD", "HDR", "BluRay", "BDRip", "BRRip",
+            "WEB-DL", "WEBRip", "WEB", "HDTV", "DVDRip", "x264", "x265", "H.264", "H.265", "H264",
+            "H265", "HEVC", "10bit", "AAC", "AC3", "DTS", "DD5.1", "REMUX", "PROPER", "REPACK",
+        ];
+        NoiseWordsRule {
+            words: words.iter().map(|w| w.to_string()).collect(),
+            case_sensitive: false,
+            ignore_extension: true,
+        }
+    }
+}
+
+fn trim_leading_separators(text: &str) -> &str {
+    text.trim_start_matches(SEPARATORS)
+}
+
+pub fn noise_words(old_text: &str, rule: NoiseWordsRule) -> String {
+    let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
+
+    let mut words: Vec<&String> = rule.words.iter().filter(|w| !w.is_empty()).collect();
+    if words.is_empty() || name_to_process.is_empty() {
+        return old_text.to_string();
+    }
+    // 长词优先，避免 "WEB" 抢先匹配 "WEB-DL" 的前半部分
+    words.sort_by_key(|w| std::cmp::Reverse(w.chars().count()));
+    let alternation = words
+        .iter()
+        .map(|w| regex::escape(w))
+        .collect::<Vec<_>>()
+        .join("|");
+    let Ok(re) = RegexBuilder::new(&format!(r"\b(?:{})\b", alternation))
+        .case_insensitive(!rule.case_sensitive)
+        .build()
+    else {
+        return old_text.to_string();
+    };
+
+    // 移除噪音词时顺带整理接缝处的分隔符：
+    // 左右两侧都有分隔符时只保留左侧，位于开头或结尾时两侧的分隔符都去掉
+    let mut result = String::new();
+    let mut last = 0;
+    let mut removed_any = false;
+    for m in re.find_iter(&name_to_process) {
+        let segment = &name_to_process[last..m.start()];
+        let segment = if removed_any && (result.is_empty() || result.ends_with(SEPARATORS)) {
+            trim_leading_separators(segment)
+        } else {
+            segment
+        };
+        result.push_str(segment);
+        last = m.end();
+        removed_any = true;
+    }
+    if !removed_any {
+        return old_text.to_string();
+    }
+
+    let tail = &name_to_process[last..];
+    if result.is_empty() || result.ends_with(SEPARATORS) {
+        result.push_str(trim_leading_separators(tail));
+    } else {
+        result.push_str(tail);
+    }
+    if last == name_to_process.len() {
+        result = result.trim_end_matches(SEPARATORS).to_string();
+    }
+
+    format!("{}{}", result, extension_to_append)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：使用内置视频噪音词清理常见的资源名
+    #[rstest]
+    #[case("Movie Name (2021) 1080p BluRay x264.mkv", "Movie Name (2021).mkv")]
+    #[case("Movie Name (2021) 1080p BluRay x264", "Movie Name (2021)")]
+    #[case("1080p.Movie.Name.(2021).x265.HEVC.mkv", "Movie.Name.(2021).mkv")]
+    #[case("Show - bluray - Episode.mkv", "Show - Episode.mkv")]
+    #[case("Holiday Video.mp4", "Holiday Video.mp4")]
+    fn test_noise_words_video_defaults(#[case] input: &str, #[case] expected: &str) {
+        let result = noise_words(input, NoiseWordsRule::video_defaults());
+        assert_eq!(result, expected);
+    }
+
+    // 测试样例：自定义噪音词 + 区分大小写
+    #[rstest]
+    #[case("draft Report FINAL.docx", &["draft", "FINAL"], true, "Report.docx")]
+    #[case("Draft Report final.docx", &["draft", "FINAL"], true, "Draft Report final.docx")]
+    #[case("Draft Report final.docx", &["draft", "FINAL"], false, "Report.docx")]
+    fn test_noise_words_custom(
+        #[case] input: &str,
+        #[case] words: &[&str],
+        #[case] case_sensitive: bool,
+        #[case] expected: &str,
+    ) {
+        let rule = NoiseWordsRule {
+            words: words.iter().map(|w| w.to_string()).collect(),
+            case_sensitive,
+            ignore_extension: true,
+        };
+
+        let result = noise_words(input, rule);
+        assert_eq!(result, expected);
+    }
+}
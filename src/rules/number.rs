@@ -0,0 +1,557 @@
+use super::common::{InsertPosition, insert_text, split_name};
+use super::error::RuleError;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 编号前对文件排序的方式
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SortBy {
+    /// 保持输入顺序
+    Input,
+    /// 按完整文件名排序
+    Name,
+    /// 按排序表达式排序。
+    /// 表达式由逗号分隔的键组成，前面的键优先，相同时再比较后面的键；
+    /// 键前加 `-` 表示降序。支持的键：
+    /// - `name`: 完整文件名
+    /// - `stem`: 去掉扩展名的部分
+    /// - `ext`: 扩展名（不含点）
+    /// - `len`: 文件名的字符数
+    ///
+    /// 例如 `"ext,name"` 先按扩展名分组，再按文件名排序
+    Expr(String),
+}
+
+/// 编号计数器的作用范围
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum NumberScope {
+    /// 整批文件共用一个计数器
+    #[default]
+    Global,
+    /// 每种扩展名（不区分大小写）各自从 start 开始计数
+    PerExtension,
+}
+
+/// 奇偶位置分别编号，常用于双面扫描后正反面交错的页面
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParityNumber {
+    /// 第 1、3、5… 个文件的 (起始编号, 步长)
+    pub odd: (i64, i64),
+    /// 第 2、4、6… 个文件的 (起始编号, 步长)
+    pub even: (i64, i64),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NumberRule {
+    /// 起始编号
+    pub start: i64,
+    /// 步长
+    pub step: i64,
+    /// 编号补零后的最小宽度
+    pub padding: usize,
+    /// 编号插入的位置
+    pub position: InsertPosition,
+    /// 编号与原文件名之间的分隔符
+    pub separator: String,
+    /// 编号前的排序方式
+    pub sort_by: SortBy,
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+    /// 计数器的作用范围
+    #[serde(default)]
+    pub scope: NumberScope,
+    /// 要跳过的编号（如 4、13），遇到时顺延到下一个允许的值
+    #[serde(default)]
+    pub skip: Vec<i64>,
+    /// 奇偶位置分别使用各自的起始编号和步长，设置后 start 和 step 不再生效
+    #[serde(default)]
+    pub parity: Option<ParityNumber>,
+    /// 只替换文件名中第一个数字，保留其余部分（如相机前缀 "IMG_"）；
+    /// 文件名中没有数字时按 position 插入
+    #[serde(default)]
+    pub replace_existing: bool,
+    /// 保持现有编号（文件名中的第一个数字）不变的文件名，其余文件编号时会避开这些编号
+    #[serde(default)]
+    pub frozen: Vec<String>,
+}
+
+impl Default for NumberRule {
+    fn default() -> Self {
+        NumberRule {
+            start: 1,
+            step: 1,
+            padding: 0,
+            position: InsertPosition::Prefix,
+            separator: String::new(),
+            sort_by: SortBy::Input,
+            ignore_extension: true,
+            scope: NumberScope::Global,
+            skip: Vec::new(),
+            parity: None,
+            replace_existing: false,
+            frozen: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum SortField {
+    Name,
+    Stem,
+    Ext,
+    Len,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SortKey {
+    field: SortField,
+    descending: bool,
+}
+
+fn parse_sort_expr(expr: &str) -> Result<Vec<SortKey>, RuleError> {
+    expr.split(',')
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+        .map(|key| {
+            let (descending, name) = match key.strip_prefix('-') {
+                Some(rest) => (true, rest.trim()),
+                None => (false, key),
+            };
+            let field = match name {
+                "name" => SortField::Name,
+                "stem" => SortField::Stem,
+                "ext" => SortField::Ext,
+                "len" => SortField::Len,
+                _ => return Err(RuleError::InvalidSortKey(key.to_string())),
+            };
+            Ok(SortKey { field, descending })
+        })
+        .collect()
+}
+
+fn compare_by(a: &str, b: &str, field: SortField) -> Ordering {
+    let stem = |s: &str| {
+        Path::new(s)
+            .file_stem()
+            .map(|x| x.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    };
+    let ext = |s: &str| {
+        Path::new(s)
+            .extension()
+            .map(|x| x.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    };
+    match field {
+        SortField::Name => a.cmp(b),
+        SortField::Stem => stem(a).cmp(&stem(b)),
+        SortField::Ext => ext(a).cmp(&ext(b)),
+        SortField::Len => a.chars().count().cmp(&b.chars().count()),
+    }
+}
+
+/// 返回按 sort_by 排序后的下标顺序，排序是稳定的
+fn sorted_indices(names: &[String], sort_by: &SortBy) -> Result<Vec<usize>, RuleError> {
+    let mut indices: Vec<usize> = (0..names.len()).collect();
+    let keys = match sort_by {
+        SortBy::Input => return Ok(indices),
+        SortBy::Name => vec![SortKey {
+            field: SortField::Name,
+            descending: false,
+        }],
+        SortBy::Expr(expr) => parse_sort_expr(expr)?,
+    };
+
+    indices.sort_by(|&a, &b| {
+        keys.iter()
+            .map(|key| {
+                let ordering = compare_by(&names[a], &names[b], key.field);
+                if key.descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    });
+    Ok(indices)
+}
+
+/// 文本中第一个数字的字节区间
+fn first_number_span(text: &str) -> Option<(usize, usize)> {
+    let start = text.find(|c: char| c.is_ascii_digit())?;
+    let end = text[start..]
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or(text.len(), |len| start + len);
+    Some((start, end))
+}
+
+fn insert_number(old_text: &str, value: i64, rule: &NumberRule) -> String {
+    let number = format!("{:0width$}", value, width = rule.padding);
+    if rule.replace_existing {
+        let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
+        if let Some((start, end)) = first_number_span(&name_to_process) {
+            return format!(
+                "{}{}{}{}",
+                &name_to_process[..start],
+                number,
+                &name_to_process[end..],
+                extension_to_append
+            );
+        }
+    }
+    insert_text(
+        old_text,
+        &number,
+        rule.position,
+        &rule.separator,
+        rule.ignore_extension,
+    )
+}
+
+/// 为一批文件名编号。编号按排序后的位置分配，但返回结果保持输入顺序。
+/// frozen 中的文件保持原样，它们的现有编号和 skip 一样会被其余文件跳过
+pub fn number(names: &[String], rule: NumberRule) -> Result<Vec<String>, RuleError> {
+    let order = sorted_indices(names, &rule.sort_by)?;
+    let is_frozen = |name: &String| rule.frozen.contains(name);
+    let frozen_numbers: Vec<i64> = names
+        .iter()
+        .filter(|name| is_frozen(name))
+        .filter_map(|name| {
+            let (name_to_process, _) = split_name(name, rule.ignore_extension);
+            let (start, end) = first_number_span(&name_to_process)?;
+            name_to_process[start..end].parse().ok()
+        })
+        .collect();
+
+    let mut results = names.to_vec();
+    // 每个计数器范围内的状态：已编号的文件数，以及奇/偶两个序列的下一个候选值
+    let (odd, even) = match rule.parity {
+        Some(parity) => (parity.odd, parity.even),
+        None => ((rule.start, rule.step), (rule.start, rule.step)),
+    };
+    let mut counters: HashMap<String, (usize, [i64; 2])> = HashMap::new();
+    for &index in &order {
+        if is_frozen(&names[index]) {
+            continue;
+        }
+        let scope_key = match rule.scope {
+            NumberScope::Global => String::new(),
+            NumberScope::PerExtension => Path::new(&names[index])
+                .extension()
+                .map(|e| e.to_string_lossy().to_lowercase())
+                .unwrap_or_default(),
+        };
+        let (count, next) = counters.entry(scope_key).or_insert((0, [odd.0, even.0]));
+        // 未设置 parity 时所有文件共用第一个序列
+        let track = if rule.parity.is_some() { *count % 2 } else { 0 };
+        let step = [odd.1, even.1][track];
+        *count += 1;
+
+        let mut value = next[track];
+        while rule.skip.contains(&value) || frozen_numbers.contains(&value) {
+            // 步长为 0 时无法顺延，跳过的编号又不能输出
+            if step == 0 {
+                return Err(RuleError::UnskippableNumber(value));
+            }
+            value += step;
+        }
+        next[track] = value + step;
+        results[index] = insert_number(&names[index], value, &rule);
+    }
+    Ok(results)
+}
+
+/// 按文件修改时间从早到晚编号，修改时间相同时保持输入顺序。
+/// 会忽略 rule.sort_by，返回的文件名与 paths 一一对应
+pub fn number_by_mtime(paths: &[PathBuf], rule: NumberRule) -> Result<Vec<String>, RuleError> {
+    let mut entries = Vec::with_capacity(paths.len());
+    for (index, path) in paths.iter().enumerate() {
+        let modified = fs::metadata(path)?.modified()?;
+        let name = path
+            .file_name()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        entries.push((modified, index, name));
+    }
+    entries.sort_by_key(|&(modified, index, _)| (modified, index));
+
+    let sorted_names: Vec<String> = entries.iter().map(|(_, _, name)| name.clone()).collect();
+    let numbered = number(
+        &sorted_names,
+        NumberRule {
+            sort_by: SortBy::Input,
+            ..rule
+        },
+    )?;
+
+    let mut results = vec![String::new(); paths.len()];
+    for ((_, index, _), name) in entries.into_iter().zip(numbered) {
+        results[index] = name;
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    // 测试样例：按输入顺序编号 + 补零 + 前/后缀
+    #[rstest]
+    #[case(InsertPosition::Prefix, &["a.txt", "b.txt"], &["01_a.txt", "02_b.txt"])]
+    #[case(InsertPosition::Suffix, &["a.txt", "b.txt"], &["a_01.txt", "b_02.txt"])]
+    fn test_number_input_order(
+        #[case] position: InsertPosition,
+        #[case] input: &[&str],
+        #[case] expected: &[&str],
+    ) {
+        let rule = NumberRule {
+            padding: 2,
+            position,
+            separator: "_".to_string(),
+            ..Default::default()
+        };
+
+        let result = number(&names(input), rule).unwrap();
+        assert_eq!(result, names(expected));
+    }
+
+    // 测试样例：按表达式排序后编号，结果保持输入顺序
+    #[rstest]
+    #[case(
+        "ext,name",
+        &["b.png", "c.jpg", "a.png", "a.jpg"],
+        &["4_b.png", "2_c.jpg", "3_a.png", "1_a.jpg"]
+    )]
+    #[case(
+        "-ext,name",
+        &["b.png", "c.jpg", "a.png", "a.jpg"],
+        &["2_b.png", "4_c.jpg", "1_a.png", "3_a.jpg"]
+    )]
+    #[case("len,stem", &["ccc.txt", "a.txt", "bb.txt"], &["3_ccc.txt", "1_a.txt", "2_bb.txt"])]
+    fn test_number_sort_expr(
+        #[case] expr: &str,
+        #[case] input: &[&str],
+        #[case] expected: &[&str],
+    ) {
+        let rule = NumberRule {
+            separator: "_".to_string(),
+            sort_by: SortBy::Expr(expr.to_string()),
+            ..Default::default()
+        };
+
+        let result = number(&names(input), rule).unwrap();
+        assert_eq!(result, names(expected));
+    }
+
+    // 测试样例：按扩展名分别计数，交错的 jpg/png 各自从头开始
+    #[rstest]
+    #[case(
+        &["a.jpg", "b.png", "c.jpg", "d.png", "e.JPG"],
+        &["a_001.jpg", "b_001.png", "c_002.jpg", "d_002.png", "e_003.JPG"]
+    )]
+    #[case(&["readme", "a.txt", "license"], &["readme_001", "a_001.txt", "license_002"])]
+    fn test_number_per_extension(#[case] input: &[&str], #[case] expected: &[&str]) {
+        let rule = NumberRule {
+            padding: 3,
+            position: InsertPosition::Suffix,
+            separator: "_".to_string(),
+            scope: NumberScope::PerExtension,
+            ..Default::default()
+        };
+
+        let result = number(&names(input), rule).unwrap();
+        assert_eq!(result, names(expected));
+    }
+
+    // 测试样例：跳过保留的编号，补零作用于实际输出的编号
+    #[rstest]
+    #[case(1, &[4], 1, &["1", "2", "3", "5", "6", "7"])]
+    #[case(1, &[4, 5, 13], 1, &["1", "2", "3", "6", "7", "8"])]
+    #[case(10, &[12, 14], 2, &["10", "16", "18", "20", "22", "24"])]
+    #[case(4, &[5], 0, &["4", "4", "4", "4", "4", "4"])]
+    fn test_number_skip(
+        #[case] start: i64,
+        #[case] skip: &[i64],
+        #[case] step: i64,
+        #[case] expected: &[&str],
+    ) {
+        let rule = NumberRule {
+            start,
+            step,
+            skip: skip.to_vec(),
+            ..Default::default()
+        };
+        let input = names(&["a", "b", "c", "d", "e", "f"]);
+
+        let result = number(&input, rule).unwrap();
+        let expected: Vec<String> = input
+            .iter()
+            .zip(expected)
+            .map(|(name, n)| format!("{}{}", n, name))
+            .collect();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_number_skip_zero_step() {
+        let rule = NumberRule {
+            start: 4,
+            step: 0,
+            skip: vec![4],
+            ..Default::default()
+        };
+
+        let result = number(&names(&["a", "b"]), rule);
+        assert!(matches!(result, Err(RuleError::UnskippableNumber(4))));
+    }
+
+    #[test]
+    fn test_number_skip_padding() {
+        let rule = NumberRule {
+            padding: 2,
+            position: InsertPosition::Suffix,
+            separator: "_".to_string(),
+            skip: vec![2],
+            ..Default::default()
+        };
+
+        let result = number(&names(&["a.txt", "b.txt"]), rule).unwrap();
+        assert_eq!(result, names(&["a_01.txt", "b_03.txt"]));
+    }
+
+    // 测试样例：双面扫描，奇数位置为正面 1,3,5,7，偶数位置为倒序的背面 8,6,4,2
+    #[test]
+    fn test_number_parity() {
+        let rule = NumberRule {
+            position: InsertPosition::Suffix,
+            separator: "_".to_string(),
+            parity: Some(ParityNumber {
+                odd: (1, 2),
+                even: (8, -2),
+            }),
+            ..Default::default()
+        };
+        let input: Vec<String> = (1..=8).map(|i| format!("scan{}.png", i)).collect();
+
+        let result = number(&input, rule).unwrap();
+        let expected: Vec<String> = [1, 8, 3, 6, 5, 4, 7, 2]
+            .iter()
+            .zip(1..=8)
+            .map(|(n, i)| format!("scan{}_{}.png", i, n))
+            .collect();
+        assert_eq!(result, expected);
+    }
+
+    // 测试样例：只替换已有的第一个数字，没有数字时按位置插入
+    #[rstest]
+    #[case("IMG_0042.jpg", "IMG_001.jpg")]
+    #[case("DSC0042_edit2.jpg", "DSC001_edit2.jpg")]
+    #[case("cover.jpg", "cover_001.jpg")]
+    fn test_number_replace_existing(#[case] input: &str, #[case] expected: &str) {
+        let rule = NumberRule {
+            padding: 3,
+            position: InsertPosition::Suffix,
+            separator: "_".to_string(),
+            replace_existing: true,
+            ..Default::default()
+        };
+
+        let result = number(&names(&[input]), rule).unwrap();
+        assert_eq!(result, names(&[expected]));
+    }
+
+    // 测试样例：按修改时间重新编号并保留相机前缀
+    #[test]
+    fn test_number_by_mtime() {
+        use std::fs::File;
+        use std::time::{Duration, SystemTime};
+
+        let dir = tempfile::tempdir().unwrap();
+        let base = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let files = [
+            ("IMG_0100.jpg", 30),
+            ("IMG_0007.jpg", 10),
+            ("DSC_0500.jpg", 20),
+        ];
+        let paths: Vec<PathBuf> = files
+            .iter()
+            .map(|(name, offset)| {
+                let path = dir.path().join(name);
+                fs::write(&path, "").unwrap();
+                File::options()
+                    .write(true)
+                    .open(&path)
+                    .unwrap()
+                    .set_modified(base + Duration::from_secs(*offset))
+                    .unwrap();
+                path
+            })
+            .collect();
+
+        let rule = NumberRule {
+            padding: 4,
+            sort_by: SortBy::Name,
+            replace_existing: true,
+            ..Default::default()
+        };
+
+        let result = number_by_mtime(&paths, rule).unwrap();
+        assert_eq!(
+            result,
+            names(&["IMG_0003.jpg", "IMG_0001.jpg", "DSC_0002.jpg"])
+        );
+    }
+
+    #[test]
+    fn test_number_invalid_sort_key() {
+        let rule = NumberRule {
+            sort_by: SortBy::Expr("ext,size".to_string()),
+            ..Default::default()
+        };
+
+        let result = number(&names(&["a.txt"]), rule);
+        assert!(matches!(result, Err(RuleError::InvalidSortKey(key)) if key == "size"));
+    }
+
+    #[test]
+    fn test_number_frozen() {
+        let rule = NumberRule {
+            padding: 2,
+            separator: "_".to_string(),
+            replace_existing: true,
+            frozen: names(&["intro_02.mp3", "outro_04.mp3"]),
+            ..Default::default()
+        };
+
+        let input = names(&[
+            "a_07.mp3",
+            "intro_02.mp3",
+            "b_09.mp3",
+            "c_01.mp3",
+            "outro_04.mp3",
+            "d.mp3",
+        ]);
+        let result = number(&input, rule).unwrap();
+        assert_eq!(
+            result,
+            names(&[
+                "a_01.mp3",
+                "intro_02.mp3",
+                "b_03.mp3",
+                "c_05.mp3",
+                "outro_04.mp3",
+                "06_d.mp3",
+            ])
+        );
+    }
+}
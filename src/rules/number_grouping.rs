@@ -0,0 +1,111 @@
+use super::common::split_name;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// 移除分组时能识别的千位分隔符
+const GROUP_SEPARATORS: [char; 2] = [',', '\''];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NumberGroupingRule {
+    /// 千位分隔符，None 表示移除已有的分组
+    pub group_char: Option<char>,
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+}
+
+/// 从右往左每三位插入一个分隔符
+fn group_digits(digits: &str, group_char: char) -> String {
+    let len = digits.len();
+    let mut result = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (len - i).is_multiple_of(3) {
+            result.push(group_char);
+        }
+        result.push(c);
+    }
+    result
+}
+
+/// 判断是否为按千位分组的数字，如 "1,000,000"，要求整个数字使用同一种分隔符
+fn is_grouped(token: &str) -> bool {
+    let Some(sep) = token.chars().find(|c| GROUP_SEPARATORS.contains(c)) else {
+        return false;
+    };
+    let mut groups = token.split(sep);
+    let head = groups.next().unwrap_or_default();
+    (1..=3).contains(&head.len())
+        && head.chars().all(|c| c.is_ascii_digit())
+        && groups.all(|g| g.len() == 3 && g.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// 为数字添加或移除千位分隔符。
+/// 含有 "." 的数字（小数、版本号）以及以 0 开头的编号保持原样
+pub fn number_grouping(old_text: &str, rule: NumberGroupingRule) -> String {
+    let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
+
+    // 把数字连同其中的分隔符作为一个整体匹配，避免拆开 "1.2" 或 "1,000"
+    let re = Regex::new(r"\d+(?:[.,']\d+)*").expect("数字正则是合法的");
+    let result = re.replace_all(&name_to_process, |caps: &regex::Captures| {
+        let token = &caps[0];
+        match rule.group_char {
+            Some(c)
+                if token.len() > 3
+                    && !token.starts_with('0')
+                    && token.chars().all(|c| c.is_ascii_digit()) =>
+            {
+                group_digits(token, c)
+            }
+            None if is_grouped(token) => token.replace(GROUP_SEPARATORS, ""),
+            _ => token.to_string(),
+        }
+    });
+
+    format!("{}{}", result, extension_to_append)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：插入千位分隔符
+    #[rstest]
+    #[case("prize 1000000.txt", ',', "prize 1,000,000.txt")]
+    #[case("prize 1000000.txt", '\'', "prize 1'000'000.txt")]
+    #[case("12345 and 999.txt", ',', "12,345 and 999.txt")]
+    #[case("v1.2", ',', "v1.2")]
+    #[case("pi 3.14159.txt", ',', "pi 3.14159.txt")]
+    #[case("IMG_00012345.jpg", ',', "IMG_00012345.jpg")]
+    #[case("total 1,000,000.txt", ',', "total 1,000,000.txt")]
+    fn test_number_grouping_insert(
+        #[case] input: &str,
+        #[case] group_char: char,
+        #[case] expected: &str,
+    ) {
+        let rule = NumberGroupingRule {
+            group_char: Some(group_char),
+            ignore_extension: true,
+        };
+
+        let result = number_grouping(input, rule);
+        assert_eq!(result, expected);
+    }
+
+    // 测试样例：移除千位分隔符
+    #[rstest]
+    #[case("prize 1,000,000.txt", "prize 1000000.txt")]
+    #[case("prize 1'000'000.txt", "prize 1000000.txt")]
+    #[case("v1.2", "v1.2")]
+    #[case("1,000.50 usd.txt", "1,000.50 usd.txt")]
+    #[case("a,b 1,23.txt", "a,b 1,23.txt")]
+    #[case("mixed 1,000'000.txt", "mixed 1,000'000.txt")]
+    fn test_number_grouping_remove(#[case] input: &str, #[case] expected: &str) {
+        let rule = NumberGroupingRule {
+            group_char: None,
+            ignore_extension: true,
+        };
+
+        let result = number_grouping(input, rule);
+        assert_eq!(result, expected);
+    }
+}
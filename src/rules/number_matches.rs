@@ -0,0 +1,100 @@
+use super::common::{build_regex, split_name};
+use super::error::RuleError;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NumberMatchesRule {
+    /// 要替换为编号的正则表达式
+    pub pattern: String,
+    /// 起始编号
+    pub start: i64,
+    /// 编号补零后的最小宽度
+    pub padding: usize,
+    /// 整批文件共用一个计数器，否则每个文件都从 start 开始
+    #[serde(default)]
+    pub batch_wide: bool,
+    /// 忽略扩展名
+    #[serde(default)]
+    pub ignore_extension: bool,
+}
+
+/// 把每个文件名中依次出现的匹配替换为递增的编号，如 "a_a_a" -> "1_2_3"
+pub fn number_matches(names: &[String], rule: NumberMatchesRule) -> Result<Vec<String>, RuleError> {
+    let re = build_regex(&rule.pattern, true)?;
+
+    let mut next = rule.start;
+    let mut results = Vec::with_capacity(names.len());
+    for name in names {
+        if !rule.batch_wide {
+            next = rule.start;
+        }
+        let (name_to_process, extension_to_append) = split_name(name, rule.ignore_extension);
+        let replaced = re.replace_all(&name_to_process, |_: &regex::Captures| {
+            let number = format!("{:0width$}", next, width = rule.padding);
+            next += 1;
+            number
+        });
+        results.push(format!("{}{}", replaced, extension_to_append));
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    // 测试样例：每个文件单独计数与整批共用计数
+    #[rstest]
+    #[case(false, &["a_a_a", "a_a"], &["1_2_3", "1_2"])]
+    #[case(true, &["a_a_a", "a_a"], &["1_2_3", "4_5"])]
+    #[case(false, &["b.txt", "a.txt"], &["b.txt", "1.txt"])]
+    fn test_number_matches(
+        #[case] batch_wide: bool,
+        #[case] input: &[&str],
+        #[case] expected: &[&str],
+    ) {
+        let rule = NumberMatchesRule {
+            pattern: "a".to_string(),
+            start: 1,
+            padding: 0,
+            batch_wide,
+            ignore_extension: true,
+        };
+
+        let result = number_matches(&names(input), rule).unwrap();
+        assert_eq!(result, names(expected));
+    }
+
+    #[test]
+    fn test_number_matches_padding() {
+        let rule = NumberMatchesRule {
+            pattern: r"#+".to_string(),
+            start: 9,
+            padding: 2,
+            batch_wide: false,
+            ignore_extension: true,
+        };
+
+        let result = number_matches(&names(&["part# of ##.txt"]), rule).unwrap();
+        assert_eq!(result, names(&["part09 of 10.txt"]));
+    }
+
+    #[test]
+    fn test_number_matches_invalid_pattern() {
+        let rule = NumberMatchesRule {
+            pattern: "(".to_string(),
+            start: 1,
+            padding: 0,
+            batch_wide: false,
+            ignore_extension: true,
+        };
+
+        let result = number_matches(&names(&["a"]), rule);
+        assert!(matches!(result, Err(RuleError::InvalidPattern { .. })));
+    }
+}
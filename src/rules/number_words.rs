@@ -0,0 +1,246 @@
+use super::common::split_name;
+use regex::{Captures, Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+
+/// 超过该值的数字不转换
+const MAX_NUMBER: u64 = 999_999_999;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NumWordDir {
+    /// 数字转单词："3" -> "three"
+    ToWords,
+    /// 单词转数字："three" -> "3"
+    ToDigits,
+}
+
+/// 数字单词所用的语言，新增语言时在这里扩展
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Lang {
+    English,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NumberWordsRule {
+    /// 转换方向
+    pub direction: NumWordDir,
+    /// 语言
+    pub language: Lang,
+}
+
+const EN_ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+const EN_TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+const EN_SCALES: [(&str, u64); 2] = [("million", 1_000_000), ("thousand", 1_000)];
+
+fn en_below_thousand(n: u64) -> String {
+    let mut parts = Vec::new();
+    if n >= 100 {
+        parts.push(format!("{} hundred", EN_ONES[(n / 100) as usize]));
+    }
+    let rest = n % 100;
+    if rest >= 20 {
+        let tens = EN_TENS[(rest / 10) as usize];
+        match rest % 10 {
+            0 => parts.push(tens.to_string()),
+            ones => parts.push(format!("{}-{}", tens, EN_ONES[ones as usize])),
+        }
+    } else if rest > 0 || n == 0 {
+        parts.push(EN_ONES[rest as usize].to_string());
+    }
+    parts.join(" ")
+}
+
+fn en_to_words(mut n: u64) -> String {
+    if n == 0 {
+        return EN_ONES[0].to_string();
+    }
+    let mut parts = Vec::new();
+    for (name, scale) in EN_SCALES {
+        if n >= scale {
+            parts.push(format!("{} {}", en_below_thousand(n / scale), name));
+            n %= scale;
+        }
+    }
+    if n > 0 {
+        parts.push(en_below_thousand(n));
+    }
+    parts.join(" ")
+}
+
+fn en_word_value(word: &str) -> Option<u64> {
+    EN_ONES
+        .iter()
+        .position(|w| *w == word)
+        .or_else(|| {
+            EN_TENS
+                .iter()
+                .position(|w| !w.is_empty() && *w == word)
+                .map(|i| i * 10)
+        })
+        .map(|v| v as u64)
+}
+
+/// 累加过程中任何一步超过 MAX_NUMBER 都返回 None，
+/// 避免 "hundred hundred …" 这类重复的量级单词导致溢出
+fn en_parse(text: &str) -> Option<u64> {
+    let clamp = |n: Option<u64>| n.filter(|&n| n <= MAX_NUMBER);
+    let mut total: u64 = 0;
+    let mut current: u64 = 0;
+    for word in text
+        .split([' ', '-'])
+        .filter(|w| !w.is_empty() && *w != "and")
+    {
+        match word {
+            "hundred" => current = clamp(current.max(1).checked_mul(100))?,
+            _ => match EN_SCALES.iter().find(|(name, _)| *name == word) {
+                Some((_, scale)) => {
+                    let scaled = current.max(1).checked_mul(*scale);
+                    total = clamp(scaled.and_then(|n| total.checked_add(n)))?;
+                    current = 0;
+                }
+                None => current = clamp(current.checked_add(en_word_value(word)?))?,
+            },
+        }
+    }
+    clamp(total.checked_add(current))
+}
+
+impl Lang {
+    fn spell(self, n: u64) -> String {
+        match self {
+            Lang::English => en_to_words(n),
+        }
+    }
+
+    /// 解析数字单词序列，返回 None 表示不是合法的数字写法
+    fn parse(self, text: &str) -> Option<u64> {
+        let value = match self {
+            Lang::English => en_parse(text)?,
+        };
+        // 用规范写法回验，排除 "one two" 这类能累加但并不合法的序列
+        let normalize = |s: &str| {
+            s.split([' ', '-'])
+                .filter(|w| !w.is_empty() && *w != "and")
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        (normalize(text) == normalize(&self.spell(value))).then_some(value)
+    }
+
+    fn words_regex(self) -> Regex {
+        let words: Vec<&str> = match self {
+            Lang::English => EN_ONES
+                .iter()
+                .chain(EN_TENS.iter().filter(|w| !w.is_empty()))
+                .chain(["hundred", "thousand", "million"].iter())
+                .copied()
+                .collect(),
+        };
+        let word = format!("(?:{})", words.join("|"));
+        RegexBuilder::new(&format!(
+            r"\b{word}(?:[\s-]+(?:and[\s-]+)?{word})*\b",
+            word = word
+        ))
+        .case_insensitive(true)
+        .build()
+        .expect("数字单词正则是合法的")
+    }
+}
+
+pub fn number_words(old_text: &str, rule: NumberWordsRule) -> String {
+    let (stem, extension) = split_name(old_text, true);
+
+    let converted = match rule.direction {
+        NumWordDir::ToWords => {
+            let re = Regex::new(r"\b\d+\b").expect("数字正则是合法的");
+            re.replace_all(&stem, |caps: &Captures| match caps[0].parse::<u64>() {
+                Ok(n) if n <= MAX_NUMBER => rule.language.spell(n),
+                _ => caps[0].to_string(), // 超出范围的数字保持原样
+            })
+            .into_owned()
+        }
+        NumWordDir::ToDigits => rule
+            .language
+            .words_regex()
+            .replace_all(&stem, |caps: &Captures| {
+                match rule.language.parse(&caps[0].to_lowercase()) {
+                    Some(n) => n.to_string(),
+                    None => caps[0].to_string(),
+                }
+            })
+            .into_owned(),
+    };
+
+    format!("{}{}", converted, extension)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：数字转英文单词
+    #[rstest]
+    #[case("chapter 3", "chapter three")]
+    #[case("chapter 3.mp3", "chapter three.mp3")]
+    #[case("part 21", "part twenty-one")]
+    #[case("page 0", "page zero")]
+    #[case("vol 115", "vol one hundred fifteen")]
+    #[case("year 2023", "year two thousand twenty-three")]
+    #[case("id 1000000000", "id 1000000000")]
+    fn test_number_words_to_words(#[case] input: &str, #[case] expected: &str) {
+        let rule = NumberWordsRule {
+            direction: NumWordDir::ToWords,
+            language: Lang::English,
+        };
+
+        let result = number_words(input, rule);
+        assert_eq!(result, expected);
+    }
+
+    // 测试样例：英文单词转数字
+    #[rstest]
+    #[case("chapter three", "chapter 3")]
+    #[case("Chapter Three.pdf", "Chapter 3.pdf")]
+    #[case("part twenty-one", "part 21")]
+    #[case("vol one hundred and fifteen", "vol 115")]
+    #[case("two thousand twenty-three", "2023")]
+    #[case("one two", "one two")]
+    #[case("someone", "someone")]
+    #[case(
+        "hundred hundred hundred hundred hundred hundred hundred hundred hundred hundred.txt",
+        "hundred hundred hundred hundred hundred hundred hundred hundred hundred hundred.txt"
+    )]
+    #[case("million million million.txt", "million million million.txt")]
+    fn test_number_words_to_digits(#[case] input: &str, #[case] expected: &str) {
+        let rule = NumberWordsRule {
+            direction: NumWordDir::ToDigits,
+            language: Lang::English,
+        };
+
+        let result = number_words(input, rule);
+        assert_eq!(result, expected);
+    }
+}
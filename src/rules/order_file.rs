@@ -0,0 +1,167 @@
+use super::common::{render_template, split_name};
+use super::error::RuleError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// 不在顺序文件中的文件如何处理
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Unlisted {
+    /// 保持原文件名
+    #[default]
+    Keep,
+    /// 按输入顺序排到顺序文件列出的文件之后继续编号
+    End,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderFileRule {
+    /// 顺序文件，每行一个文件名（可带或不带扩展名），空行会被忽略
+    pub order_path: PathBuf,
+    /// 新文件名模板（不含扩展名），支持以下占位符：
+    /// - `{n}`: 文件在顺序中的位置，从 1 开始；`{n:3}` 补零到 3 位
+    /// - `{name}`: 原文件名去掉扩展名的部分
+    ///
+    /// `{{` 和 `}}` 表示字面的花括号
+    pub template: String,
+    /// 不在顺序文件中的文件如何处理
+    #[serde(default)]
+    pub unlisted: Unlisted,
+}
+
+fn render(template: &str, position: usize, stem: &str) -> Result<String, RuleError> {
+    render_template(template, |token| match token.split_once(':') {
+        None if token == "n" => Ok(position.to_string()),
+        None if token == "name" => Ok(stem.to_string()),
+        Some(("n", width)) => {
+            let width: usize = width
+                .parse()
+                .map_err(|_| RuleError::UnknownToken(token.to_string()))?;
+            Ok(format!("{:0width$}", position, width = width))
+        }
+        _ => Err(RuleError::UnknownToken(token.to_string())),
+    })
+}
+
+/// 按顺序文件中的行号为文件重新编号，扩展名保持不变。
+/// 顺序文件中的一行可以匹配完整文件名，也可以只匹配去掉扩展名的部分
+pub fn order_file(names: &[String], rule: OrderFileRule) -> Result<Vec<String>, RuleError> {
+    let content = fs::read_to_string(&rule.order_path)?;
+    let mut order: HashMap<&str, usize> = HashMap::new();
+    for line in content.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        let next = order.len() + 1;
+        order.entry(line).or_insert(next);
+    }
+
+    let mut next_unlisted = order.len() + 1;
+    names
+        .iter()
+        .map(|name| {
+            let (stem, extension) = split_name(name, true);
+            let listed = order
+                .get(name.as_str())
+                .or_else(|| order.get(stem.as_str()))
+                .copied();
+            let position = match (listed, rule.unlisted) {
+                (Some(position), _) => position,
+                (None, Unlisted::Keep) => return Ok(name.clone()),
+                (None, Unlisted::End) => {
+                    next_unlisted += 1;
+                    next_unlisted - 1
+                }
+            };
+            Ok(format!(
+                "{}{}",
+                render(&rule.template, position, &stem)?,
+                extension
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn order(dir: &std::path::Path) -> PathBuf {
+        let path = dir.join("order.txt");
+        fs::write(&path, "intro.mp4\nchapter-2\n\nchapter-1.mp4\noutro\n").unwrap();
+        path
+    }
+
+    // 测试样例：按顺序文件的行号编号，未列出的文件保持原样或排到末尾
+    #[rstest]
+    #[case(
+        Unlisted::Keep,
+        &["chapter-1.mp4", "extra.mp4", "outro.mp4", "intro.mp4", "chapter-2.mkv"],
+        &["03_chapter-1.mp4", "extra.mp4", "04_outro.mp4", "01_intro.mp4", "02_chapter-2.mkv"]
+    )]
+    #[case(
+        Unlisted::End,
+        &["b-roll.mp4", "outro.mp4", "extra.mp4", "intro.mp4"],
+        &["05_b-roll.mp4", "04_outro.mp4", "06_extra.mp4", "01_intro.mp4"]
+    )]
+    fn test_order_file(
+        #[case] unlisted: Unlisted,
+        #[case] input: &[&str],
+        #[case] expected: &[&str],
+    ) {
+        let dir = tempfile::tempdir().unwrap();
+        let rule = OrderFileRule {
+            order_path: order(dir.path()),
+            template: "{n:2}_{name}".to_string(),
+            unlisted,
+        };
+
+        let result = order_file(&names(input), rule).unwrap();
+        assert_eq!(result, names(expected));
+    }
+
+    #[test]
+    fn test_order_file_unknown_token() {
+        let dir = tempfile::tempdir().unwrap();
+        let rule = OrderFileRule {
+            order_path: order(dir.path()),
+            template: "{index}".to_string(),
+            unlisted: Unlisted::Keep,
+        };
+
+        let result = order_file(&names(&["intro.mp4"]), rule);
+        assert!(matches!(result, Err(RuleError::UnknownToken(t)) if t == "index"));
+    }
+
+    #[test]
+    fn test_order_file_template_braces() {
+        let dir = tempfile::tempdir().unwrap();
+        let rule = |template: &str| OrderFileRule {
+            order_path: order(dir.path()),
+            template: template.to_string(),
+            unlisted: Unlisted::Keep,
+        };
+
+        let result = order_file(&names(&["intro.mp4"]), rule("{{{n}}} {name}")).unwrap();
+        assert_eq!(result, names(&["{1} intro.mp4"]));
+
+        let result = order_file(&names(&["intro.mp4"]), rule("{n:2"));
+        assert!(matches!(result, Err(RuleError::UnterminatedToken(t)) if t == "{n:2"));
+    }
+
+    #[test]
+    fn test_order_file_missing() {
+        let dir = tempfile::tempdir().unwrap();
+        let rule = OrderFileRule {
+            order_path: dir.path().join("missing.txt"),
+            template: "{n}".to_string(),
+            unlisted: Unlisted::Keep,
+        };
+
+        let result = order_file(&names(&["a.txt"]), rule);
+        assert!(matches!(result, Err(RuleError::Io(_))));
+    }
+}
@@ -0,0 +1,93 @@
+use super::common::split_name;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrdinalRule {
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+}
+
+/// 英文序数词后缀，11、12、13 结尾的数字例外地使用 "th"
+fn ordinal_suffix(digits: &str) -> &'static str {
+    let bytes = digits.as_bytes();
+    let last = bytes[bytes.len() - 1];
+    let tens = bytes.len() >= 2 && bytes[bytes.len() - 2] == b'1';
+    match (tens, last) {
+        (true, _) => "th",
+        (false, b'1') => "st",
+        (false, b'2') => "nd",
+        (false, b'3') => "rd",
+        _ => "th",
+    }
+}
+
+/// 为独立的数字加上英文序数后缀，如 "1" -> "1st"、"11" -> "11th"。
+/// 紧邻字母的数字（如 "1st"、"x264"）以及小数、版本号保持原样
+pub fn ordinal(old_text: &str, rule: OrdinalRule) -> String {
+    let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
+
+    let re = Regex::new(r"\d+(?:[.,]\d+)*").expect("数字正则是合法的");
+    let result = re.replace_all(&name_to_process, |caps: &regex::Captures| {
+        let m = caps.get(0).unwrap();
+        let token = m.as_str();
+        let touches_letter = name_to_process[..m.start()]
+            .chars()
+            .next_back()
+            .is_some_and(char::is_alphabetic)
+            || name_to_process[m.end()..]
+                .chars()
+                .next()
+                .is_some_and(char::is_alphabetic);
+        if touches_letter || !token.chars().all(|c| c.is_ascii_digit()) {
+            return token.to_string();
+        }
+        format!("{}{}", token, ordinal_suffix(token))
+    });
+
+    format!("{}{}", result, extension_to_append)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：序数后缀及 11/12/13 的例外
+    #[rstest]
+    #[case("1", "1st")]
+    #[case("2", "2nd")]
+    #[case("3", "3rd")]
+    #[case("4", "4th")]
+    #[case("11", "11th")]
+    #[case("12", "12th")]
+    #[case("13", "13th")]
+    #[case("21", "21st")]
+    #[case("112", "112th")]
+    #[case("1000", "1000th")]
+    fn test_ordinal_suffix(#[case] input: &str, #[case] expected: &str) {
+        let result = ordinal(
+            input,
+            OrdinalRule {
+                ignore_extension: true,
+            },
+        );
+        assert_eq!(result, expected);
+    }
+
+    // 测试样例：只处理独立的数字
+    #[rstest]
+    #[case("Chapter 2 - Part 3.txt", "Chapter 2nd - Part 3rd.txt")]
+    #[case("2nd edition.pdf", "2nd edition.pdf")]
+    #[case("movie x264 v1.2.mkv", "movie x264 v1.2.mkv")]
+    #[case("floor_21_plan.png", "floor_21st_plan.png")]
+    fn test_ordinal(#[case] input: &str, #[case] expected: &str) {
+        let result = ordinal(
+            input,
+            OrdinalRule {
+                ignore_extension: true,
+            },
+        );
+        assert_eq!(result, expected);
+    }
+}
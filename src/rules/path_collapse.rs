@@ -0,0 +1,49 @@
+use super::common::split_name;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PathCollapseRule {
+    /// 路径片段之间的分隔符，如 '_'
+    pub separator: char,
+    /// 保留末尾的片段数量
+    pub keep_last: usize,
+}
+
+/// 把 "folder_subfolder_file" 这类带路径的文件名折叠为最后 keep_last 个片段，扩展名保持不变。
+/// 片段数不超过 keep_last 或 keep_last 为 0 时保持原样
+pub fn path_collapse(old_text: &str, rule: PathCollapseRule) -> String {
+    let (name_to_process, extension_to_append) = split_name(old_text, true);
+
+    let segments: Vec<&str> = name_to_process.split(rule.separator).collect();
+    if rule.keep_last == 0 || segments.len() <= rule.keep_last {
+        return old_text.to_string();
+    }
+
+    let kept = segments[segments.len() - rule.keep_last..].join(&rule.separator.to_string());
+    format!("{}{}", kept, extension_to_append)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：不同的保留片段数
+    #[rstest]
+    #[case("a_b_c", 1, "c")]
+    #[case("a_b_c", 2, "b_c")]
+    #[case("a_b_c", 3, "a_b_c")]
+    #[case("a_b_c", 5, "a_b_c")]
+    #[case("a_b_c", 0, "a_b_c")]
+    #[case("docs_2023_report.final.pdf", 1, "report.final.pdf")]
+    #[case("nosep.txt", 1, "nosep.txt")]
+    fn test_path_collapse(#[case] input: &str, #[case] keep_last: usize, #[case] expected: &str) {
+        let rule = PathCollapseRule {
+            separator: '_',
+            keep_last,
+        };
+
+        let result = path_collapse(input, rule);
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,404 @@
+use super::Rule;
+use super::case::{CaseKind, CaseRule};
+use super::common::InsertPosition;
+use super::error::ParseError;
+use super::number::NumberRule;
+use super::regex_replace::{RegexReplaceRule, SedRule};
+use super::remove::{RemovePosition, RemoveRule};
+use super::slugify::SlugifyRule;
+use super::truncate::TruncateRule;
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// 规则名、参数名或参数值（裸文本或带引号的字符串）
+    Text(String),
+    Pipe,
+    Colon,
+    Comma,
+    Equals,
+}
+
+/// 把表达式切分为带字符位置的记号。裸文本会去掉首尾空白；
+/// 带引号的字符串原样保留，支持 `\"` 和 `\\` 转义
+fn tokenize(spec: &str) -> Result<Vec<(usize, Token)>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut bare = String::new();
+    let mut bare_start = 0;
+    let flush = |bare: &mut String, start: usize, tokens: &mut Vec<(usize, Token)>| {
+        let text = bare.trim();
+        if !text.is_empty() {
+            tokens.push((start, Token::Text(text.to_string())));
+        }
+        bare.clear();
+    };
+
+    let mut chars = spec.chars().enumerate();
+    while let Some((i, c)) = chars.next() {
+        let punct = match c {
+            '|' => Some(Token::Pipe),
+            ':' => Some(Token::Colon),
+            ',' => Some(Token::Comma),
+            '=' => Some(Token::Equals),
+            _ => None,
+        };
+        if let Some(punct) = punct {
+            flush(&mut bare, bare_start, &mut tokens);
+            tokens.push((i, punct));
+        } else if c == '"' {
+            flush(&mut bare, bare_start, &mut tokens);
+            let mut quoted = String::new();
+            loop {
+                match chars.next() {
+                    Some((_, '"')) => break,
+                    Some((_, '\\')) => match chars.next() {
+                        Some((_, escaped @ ('"' | '\\'))) => quoted.push(escaped),
+                        Some((_, other)) => {
+                            quoted.push('\\');
+                            quoted.push(other);
+                        }
+                        None => quoted.push('\\'),
+                    },
+                    Some((_, other)) => quoted.push(other),
+                    None => {
+                        return Err(ParseError::Syntax {
+                            position: i,
+                            reason: "引号未闭合".to_string(),
+                        });
+                    }
+                }
+            }
+            tokens.push((i, Token::Text(quoted)));
+        } else {
+            if bare.trim().is_empty() {
+                bare_start = i;
+            }
+            bare.push(c);
+        }
+    }
+    flush(&mut bare, bare_start, &mut tokens);
+    Ok(tokens)
+}
+
+/// 一段规则的参数：位置参数按出现顺序被依次取用，命名参数按名称取用
+struct Args {
+    stage: usize,
+    positional: VecDeque<String>,
+    named: Vec<(String, String)>,
+}
+
+impl Args {
+    fn named(&mut self, key: &str) -> Option<String> {
+        let index = self.named.iter().position(|(k, _)| k == key)?;
+        Some(self.named.remove(index).1)
+    }
+
+    /// 取命名参数，没有时取下一个位置参数
+    fn value(&mut self, key: &str) -> Option<String> {
+        self.named(key).or_else(|| self.positional.pop_front())
+    }
+
+    fn required(&mut self, key: &str) -> Result<String, ParseError> {
+        self.value(key).ok_or_else(|| ParseError::MissingArgument {
+            stage: self.stage,
+            name: key.to_string(),
+        })
+    }
+
+    fn invalid(&self, key: &str, value: String) -> ParseError {
+        ParseError::InvalidValue {
+            stage: self.stage,
+            name: key.to_string(),
+            value,
+        }
+    }
+
+    fn parse<T: FromStr>(&mut self, key: &str, default: T) -> Result<T, ParseError> {
+        match self.named(key) {
+            Some(value) => value.parse().map_err(|_| self.invalid(key, value)),
+            None => Ok(default),
+        }
+    }
+
+    fn choice<T: Clone>(
+        &mut self,
+        key: &str,
+        options: &[(&str, T)],
+        default: T,
+    ) -> Result<T, ParseError> {
+        let Some(value) = self.named(key) else {
+            return Ok(default);
+        };
+        options
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(&value))
+            .map(|(_, option)| option.clone())
+            .ok_or_else(|| self.invalid(key, value))
+    }
+
+    /// 所有参数都应已被取用，剩下的即为规则不支持的参数
+    fn finish(mut self) -> Result<(), ParseError> {
+        let leftover = match self.named.first() {
+            Some((key, _)) => Some(key.clone()),
+            None => self.positional.pop_front(),
+        };
+        match leftover {
+            Some(name) => Err(ParseError::UnknownArgument {
+                stage: self.stage,
+                name,
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+const POSITIONS: [(&str, InsertPosition); 2] = [
+    ("prefix", InsertPosition::Prefix),
+    ("suffix", InsertPosition::Suffix),
+];
+
+fn build_rule(name: &str, args: &mut Args) -> Result<Rule, ParseError> {
+    let case = |case: CaseKind, args: &mut Args| -> Result<Rule, ParseError> {
+        Ok(Rule::Case(CaseRule {
+            case,
+            ignore_extension: args.parse("ignore_ext", true)?,
+            preserve_acronyms: args.parse("acronyms", false)?,
+        }))
+    };
+
+    match name {
+        "remove" => Ok(Rule::Remove(RemoveRule {
+            text: args.required("text")?,
+            remove_position: args.choice(
+                "pos",
+                &[
+                    ("all", RemovePosition::All),
+                    ("first", RemovePosition::First),
+                    ("last", RemovePosition::Last),
+                ],
+                RemovePosition::All,
+            )?,
+            case_sensitive: args.parse("case", true)?,
+            ignore_extension: args.parse("ignore_ext", true)?,
+            ..Default::default()
+        })),
+        "lower" => case(CaseKind::Lower, args),
+        "upper" => case(CaseKind::Upper, args),
+        "title" => case(CaseKind::Title, args),
+        "number" => {
+            let default = NumberRule::default();
+            Ok(Rule::Number(NumberRule {
+                start: args.parse("start", default.start)?,
+                step: args.parse("step", default.step)?,
+                padding: args.parse("pad", default.padding)?,
+                position: args.choice("pos", &POSITIONS, default.position)?,
+                separator: args.named("sep").unwrap_or_default(),
+                ignore_extension: args.parse("ignore_ext", default.ignore_extension)?,
+                ..default
+            }))
+        }
+        "replace" => Ok(Rule::RegexReplace(RegexReplaceRule {
+            pattern: args.required("pattern")?,
+            replacement: args.value("with").unwrap_or_default(),
+            case_sensitive: args.parse("case", true)?,
+            replace_all: args.parse("all", true)?,
+            ignore_extension: args.parse("ignore_ext", true)?,
+        })),
+        "sed" => Ok(Rule::Sed(SedRule {
+            expression: args.required("expr")?,
+        })),
+        "slug" => Ok(Rule::Slugify(SlugifyRule {
+            separator: args.parse("sep", '-')?,
+            lowercase: args.parse("lower", true)?,
+            ignore_extension: args.parse("ignore_ext", true)?,
+        })),
+        "truncate" => {
+            let max = args.required("max")?;
+            Ok(Rule::Truncate(TruncateRule {
+                max_len: max.parse().map_err(|_| args.invalid("max", max))?,
+                ignore_extension: args.parse("ignore_ext", true)?,
+            }))
+        }
+        _ => Err(ParseError::UnknownRule {
+            stage: args.stage,
+            name: name.to_string(),
+        }),
+    }
+}
+
+/// 解析命令行上的简写规则链，如 `remove:"DSC_" | lower | number:start=1,pad=3`。
+///
+/// 语法：各段用 `|` 分隔，每段为 `规则名` 或 `规则名:参数,参数,...`，
+/// 参数为 `名称=值` 或按顺序对应主要参数的位置参数。
+/// 含有 `| : , =` 或首尾空白的值需要用双引号括起来。支持的规则：
+/// - `remove:文本`，可选 `pos=all|first|last`、`case=true|false`
+/// - `lower`、`upper`、`title`，`title` 可选 `acronyms=true`
+/// - `number`，可选 `start`、`step`、`pad`、`pos=prefix|suffix`、`sep`
+/// - `replace:正则,替换文本`，可选 `case`、`all`
+/// - `sed:"s/模式/替换/标志"`
+/// - `slug`，可选 `sep`、`lower`
+/// - `truncate:最大长度`
+///
+/// 除 `sed` 外都可以用 `ignore_ext=false` 让规则同时作用于扩展名
+pub fn parse_pipeline(spec: &str) -> Result<Vec<Rule>, ParseError> {
+    let mut stages: Vec<Vec<(usize, Token)>> = vec![Vec::new()];
+    for token in tokenize(spec)? {
+        match token {
+            (_, Token::Pipe) => stages.push(Vec::new()),
+            token => stages.last_mut().unwrap().push(token),
+        }
+    }
+
+    let mut rules = Vec::with_capacity(stages.len());
+    for (index, tokens) in stages.into_iter().enumerate() {
+        let stage = index + 1;
+        let mut tokens = tokens.into_iter().peekable();
+        let name = match tokens.next() {
+            Some((_, Token::Text(name))) => name,
+            Some((position, _)) => {
+                return Err(ParseError::Syntax {
+                    position,
+                    reason: "应为规则名称".to_string(),
+                });
+            }
+            None => return Err(ParseError::EmptyStage { stage }),
+        };
+
+        let mut args = Args {
+            stage,
+            positional: VecDeque::new(),
+            named: Vec::new(),
+        };
+        if let Some((position, token)) = tokens.next() {
+            if token != Token::Colon {
+                return Err(ParseError::Syntax {
+                    position,
+                    reason: "规则名称后应为 \":\"".to_string(),
+                });
+            }
+            // 参数列表：参数 ("," 参数)*，参数为 值 或 名称=值
+            loop {
+                let (position, key) = match tokens.next() {
+                    Some((position, Token::Text(text))) => (position, text),
+                    other => {
+                        return Err(ParseError::Syntax {
+                            position: position_or_end(other.map(|(p, _)| p), spec),
+                            reason: "应为参数".to_string(),
+                        });
+                    }
+                };
+                if tokens.peek().is_some_and(|(_, t)| *t == Token::Equals) {
+                    tokens.next();
+                    match tokens.next() {
+                        Some((_, Token::Text(value))) => args.named.push((key, value)),
+                        _ => {
+                            return Err(ParseError::Syntax {
+                                position,
+                                reason: format!("参数 {} 缺少值", key),
+                            });
+                        }
+                    }
+                } else {
+                    args.positional.push_back(key);
+                }
+                match tokens.next() {
+                    None => break,
+                    Some((_, Token::Comma)) => continue,
+                    Some((position, _)) => {
+                        return Err(ParseError::Syntax {
+                            position,
+                            reason: "参数之间应为 \",\"".to_string(),
+                        });
+                    }
+                }
+            }
+        }
+
+        rules.push(build_rule(&name, &mut args)?);
+        args.finish()?;
+    }
+    Ok(rules)
+}
+
+/// 记号缺失时以表达式末尾作为出错位置
+fn position_or_end(position: Option<usize>, spec: &str) -> usize {
+    position.unwrap_or_else(|| spec.chars().count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::apply_rules;
+    use rstest::rstest;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_pipeline_three_stages() {
+        let rules =
+            parse_pipeline(r#"remove:"DSC_" | lower | number:start=1,pad=3,sep="_""#).unwrap();
+        assert_eq!(rules.len(), 3);
+        assert!(matches!(
+            &rules[0],
+            Rule::Remove(RemoveRule { text, case_sensitive: true, .. }) if text == "DSC_"
+        ));
+        assert!(matches!(
+            &rules[1],
+            Rule::Case(CaseRule {
+                case: CaseKind::Lower,
+                ..
+            })
+        ));
+        assert!(matches!(
+            &rules[2],
+            Rule::Number(NumberRule {
+                start: 1,
+                padding: 3,
+                ..
+            })
+        ));
+
+        let result = apply_rules(&names(&["DSC_Beach.JPG", "DSC_Sunset.JPG"]), &rules).unwrap();
+        assert_eq!(result, names(&["001_beach.JPG", "002_sunset.JPG"]));
+    }
+
+    #[test]
+    fn test_parse_pipeline_quoted_values() {
+        let rules = parse_pipeline(r#"replace:"a|b", "x\"y", all=false | sed:"s/,/-/g""#).unwrap();
+        assert!(matches!(
+            &rules[0],
+            Rule::RegexReplace(RegexReplaceRule { pattern, replacement, replace_all: false, .. })
+                if pattern == "a|b" && replacement == "x\"y"
+        ));
+        assert!(matches!(&rules[1], Rule::Sed(SedRule { expression }) if expression == "s/,/-/g"));
+    }
+
+    // 测试样例：各类解析错误
+    #[rstest]
+    #[case("", ParseError::EmptyStage { stage: 1 })]
+    #[case("lower |", ParseError::EmptyStage { stage: 2 })]
+    #[case("lower | frobnicate", ParseError::UnknownRule { stage: 2, name: "frobnicate".to_string() })]
+    #[case("remove", ParseError::MissingArgument { stage: 1, name: "text".to_string() })]
+    #[case("lower:foo=1", ParseError::UnknownArgument { stage: 1, name: "foo".to_string() })]
+    #[case("truncate:10,20", ParseError::UnknownArgument { stage: 1, name: "20".to_string() })]
+    #[case("number:start=x", ParseError::InvalidValue { stage: 1, name: "start".to_string(), value: "x".to_string() })]
+    #[case("number:pos=middle", ParseError::InvalidValue { stage: 1, name: "pos".to_string(), value: "middle".to_string() })]
+    fn test_parse_pipeline_errors(#[case] spec: &str, #[case] expected: ParseError) {
+        assert_eq!(parse_pipeline(spec).unwrap_err(), expected);
+    }
+
+    #[rstest]
+    #[case(r#"remove:"abc"#, 7)]
+    #[case("number:start=1=2", 14)]
+    #[case("lower:", 6)]
+    #[case(r#"lower "x""#, 6)]
+    fn test_parse_pipeline_syntax_errors(#[case] spec: &str, #[case] expected_position: usize) {
+        let result = parse_pipeline(spec);
+        assert!(
+            matches!(result, Err(ParseError::Syntax { position, .. }) if position == expected_position)
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use super::common::split_name;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PunctuationNormalizeRule {
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+}
+
+/// 把文字处理软件常见的排版标点换成普通 ASCII 标点：
+/// 弯引号 -> 直引号，各种破折号 -> "-"，省略号 -> "..."
+pub fn punctuation_normalize(old_text: &str, rule: PunctuationNormalizeRule) -> String {
+    let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
+
+    let mut result = String::with_capacity(name_to_process.len());
+    for c in name_to_process.chars() {
+        match c {
+            '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{201F}' => result.push('"'),
+            '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}' => result.push('\''),
+            '\u{2010}' | '\u{2011}' | '\u{2012}' | '\u{2013}' | '\u{2014}' | '\u{2015}'
+            | '\u{2212}' => result.push('-'),
+            '\u{2026}' => result.push_str("..."),
+            _ => result.push(c),
+        }
+    }
+
+    format!("{}{}", result, extension_to_append)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：混合的排版标点
+    #[rstest]
+    #[case("“Quoted” title – draft….docx", "\"Quoted\" title - draft....docx")]
+    #[case("It’s ‘fine’ — really.txt", "It's 'fine' - really.txt")]
+    #[case("plain-name.txt", "plain-name.txt")]
+    fn test_punctuation_normalize(#[case] input: &str, #[case] expected: &str) {
+        let rule = PunctuationNormalizeRule {
+            ignore_extension: true,
+        };
+
+        let result = punctuation_normalize(input, rule);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_punctuation_normalize_not_ignore_extension() {
+        let rule = PunctuationNormalizeRule {
+            ignore_extension: false,
+        };
+
+        let result = punctuation_normalize("notes…", rule);
+        assert_eq!(result, "notes...");
+    }
+}
@@ -0,0 +1,182 @@
+use super::common::{build_regex, split_name};
+use super::error::RuleError;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegexReplaceRule {
+    /// 正则表达式
+    pub pattern: String,
+    /// 替换文本，支持 `$1`、`${name}` 等捕获组引用
+    pub replacement: String,
+    /// 区分大小写
+    pub case_sensitive: bool,
+    /// 替换所有匹配，否则只替换第一个
+    pub replace_all: bool,
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SedRule {
+    /// sed 风格的替换表达式，如 `s/foo/bar/gi`。
+    /// 分隔符为 `s` 后的第一个字符，字段中的分隔符可用 `\` 转义；
+    /// 支持 `g`（替换全部）和 `i`（不区分大小写）标志。作用于完整文件名
+    pub expression: String,
+}
+
+pub fn regex_replace(old_text: &str, rule: RegexReplaceRule) -> Result<String, RuleError> {
+    let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
+
+    let re = build_regex(&rule.pattern, rule.case_sensitive)?;
+    let replaced = if rule.replace_all {
+        re.replace_all(&name_to_process, rule.replacement.as_str())
+    } else {
+        re.replace(&name_to_process, rule.replacement.as_str())
+    };
+    Ok(format!("{}{}", replaced, extension_to_append))
+}
+
+impl SedRule {
+    /// 把 sed 表达式解析为等价的 RegexReplaceRule
+    pub fn parse(&self) -> Result<RegexReplaceRule, RuleError> {
+        let invalid = |reason: &str| RuleError::InvalidExpression {
+            expression: self.expression.clone(),
+            reason: reason.to_string(),
+        };
+
+        let mut chars = self.expression.chars();
+        if chars.next() != Some('s') {
+            return Err(invalid("必须以 s 开头"));
+        }
+        let delimiter = match chars.next() {
+            Some(c) if !c.is_alphanumeric() && c != '\\' && !c.is_whitespace() => c,
+            _ => return Err(invalid("缺少分隔符")),
+        };
+
+        // 拆分为 pattern、replacement、flags 三段，`\分隔符` 还原为分隔符本身
+        let mut fields = vec![String::new()];
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => match chars.next() {
+                    Some(next) if next == delimiter => fields.last_mut().unwrap().push(next),
+                    Some(next) => {
+                        let field = fields.last_mut().unwrap();
+                        field.push('\\');
+                        field.push(next);
+                    }
+                    None => return Err(invalid("表达式以 \\ 结尾")),
+                },
+                c if c == delimiter => fields.push(String::new()),
+                c => fields.last_mut().unwrap().push(c),
+            }
+        }
+        let [pattern, replacement, flags]: [String; 3] = fields
+            .try_into()
+            .map_err(|_| invalid("应为 s/模式/替换/标志 的形式"))?;
+        if pattern.is_empty() {
+            return Err(invalid("模式不能为空"));
+        }
+
+        let mut rule = RegexReplaceRule {
+            pattern,
+            replacement,
+            case_sensitive: true,
+            replace_all: false,
+            ignore_extension: false,
+        };
+        for flag in flags.chars() {
+            match flag {
+                'g' => rule.replace_all = true,
+                'i' => rule.case_sensitive = false,
+                _ => return Err(invalid(&format!("不支持的标志 {}", flag))),
+            }
+        }
+        Ok(rule)
+    }
+}
+
+pub fn sed(old_text: &str, rule: SedRule) -> Result<String, RuleError> {
+    regex_replace(old_text, rule.parse()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：正则替换第一个/全部匹配
+    #[rstest]
+    #[case("a1b22c333.txt", r"\d+", "#", false, "a#b22c333.txt")]
+    #[case("a1b22c333.txt", r"\d+", "#", true, "a#b#c#.txt")]
+    #[case(
+        "2023_05_01.txt",
+        r"(\d+)_(\d+)_(\d+)",
+        "$3-$2-$1",
+        true,
+        "01-05-2023.txt"
+    )]
+    fn test_regex_replace(
+        #[case] input: &str,
+        #[case] pattern: &str,
+        #[case] replacement: &str,
+        #[case] replace_all: bool,
+        #[case] expected: &str,
+    ) {
+        let rule = RegexReplaceRule {
+            pattern: pattern.to_string(),
+            replacement: replacement.to_string(),
+            case_sensitive: true,
+            replace_all,
+            ignore_extension: true,
+        };
+
+        let result = regex_replace(input, rule).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    // 测试样例：sed 表达式的标志、捕获组与转义分隔符
+    #[rstest]
+    #[case("Foo foo FOO.txt", "s/foo/bar/gi", "bar bar bar.txt")]
+    #[case("Foo foo FOO.txt", "s/foo/bar/g", "Foo bar FOO.txt")]
+    #[case("Foo foo FOO.txt", "s/foo/bar/i", "bar foo FOO.txt")]
+    #[case("IMG_1234.JPG", r"s/IMG_(\d+)\.JPG/photo-$1.jpg/", "photo-1234.jpg")]
+    #[case("a-b.txt", "s|-|/|", "a/b.txt")]
+    #[case("a-b.txt", r"s/-/\//", "a/b.txt")]
+    fn test_sed(#[case] input: &str, #[case] expression: &str, #[case] expected: &str) {
+        let rule = SedRule {
+            expression: expression.to_string(),
+        };
+
+        let result = sed(input, rule).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    // 测试样例：格式错误的表达式
+    #[rstest]
+    #[case("y/a/b/")]
+    #[case("s")]
+    #[case("s/foo/bar")]
+    #[case("s/foo/bar/g/extra")]
+    #[case("s//bar/")]
+    #[case("s/foo/bar/x")]
+    fn test_sed_malformed(#[case] expression: &str) {
+        let rule = SedRule {
+            expression: expression.to_string(),
+        };
+
+        let result = sed("foo.txt", rule);
+        assert!(
+            matches!(result, Err(RuleError::InvalidExpression { expression: e, .. }) if e == expression)
+        );
+    }
+
+    #[test]
+    fn test_sed_invalid_regex() {
+        let rule = SedRule {
+            expression: "s/(foo/bar/".to_string(),
+        };
+
+        let result = sed("foo.txt", rule);
+        assert!(matches!(result, Err(RuleError::InvalidPattern { .. })));
+    }
+}
@@ -0,0 +1,95 @@
+use super::common::{build_regex, split_name};
+use super::error::RuleError;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegexTableRule {
+    /// (正则, 替换文本) 列表，按顺序依次应用；替换文本支持 `$1`、`${name}` 等捕获组引用
+    pub entries: Vec<(String, String)>,
+    /// 区分大小写
+    pub case_sensitive: bool,
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+}
+
+/// 依次应用替换表中的每条正则替换，前一条的结果作为后一条的输入
+pub fn regex_table(old_text: &str, rule: RegexTableRule) -> Result<String, RuleError> {
+    let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
+
+    let mut result = name_to_process;
+    for (index, (pattern, replacement)) in rule.entries.iter().enumerate() {
+        let re = build_regex(pattern, rule.case_sensitive).map_err(|e| match e {
+            RuleError::InvalidPattern { pattern, reason } => RuleError::InvalidTableEntry {
+                index,
+                pattern,
+                reason,
+            },
+            e => e,
+        })?;
+        result = re.replace_all(&result, replacement.as_str()).into_owned();
+    }
+    Ok(format!("{}{}", result, extension_to_append))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn entries(list: &[(&str, &str)]) -> Vec<(String, String)> {
+        list.iter()
+            .map(|(p, r)| (p.to_string(), r.to_string()))
+            .collect()
+    }
+
+    // 测试样例：两条依次生效的正则替换，后一条作用于前一条的结果
+    #[rstest]
+    #[case(
+        "IMG_20230501_1200.jpg",
+        &[(r"IMG_(\d{4})(\d{2})(\d{2})", "$1-$2-$3"), (r"_(\d{2})(\d{2})$", " ${1}h$2")],
+        true,
+        "2023-05-01 12h00.jpg"
+    )]
+    #[case(
+        "Track 1 - Intro.mp3",
+        &[(r"track (\d+)", "$1"), (r"^(\d)\b", "0$1")],
+        false,
+        "01 - Intro.mp3"
+    )]
+    #[case(
+        "Track 1 - Intro.mp3",
+        &[(r"track (\d+)", "$1"), (r"^(\d)\b", "0$1")],
+        true,
+        "Track 1 - Intro.mp3"
+    )]
+    fn test_regex_table(
+        #[case] input: &str,
+        #[case] list: &[(&str, &str)],
+        #[case] case_sensitive: bool,
+        #[case] expected: &str,
+    ) {
+        let rule = RegexTableRule {
+            entries: entries(list),
+            case_sensitive,
+            ignore_extension: true,
+        };
+
+        let result = regex_table(input, rule).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_regex_table_invalid_entry() {
+        let rule = RegexTableRule {
+            entries: entries(&[("a", "b"), ("(unclosed", "x")]),
+            case_sensitive: true,
+            ignore_extension: true,
+        };
+
+        let result = regex_table("abc.txt", rule);
+        assert!(matches!(
+            result,
+            Err(RuleError::InvalidTableEntry { index: 1, pattern, .. }) if pattern == "(unclosed"
+        ));
+    }
+}
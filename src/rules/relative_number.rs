@@ -0,0 +1,125 @@
+use super::common::{render_template, split_name};
+use super::error::RuleError;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelativeNumberRule {
+    /// 锚点文件去掉扩展名后的名称（精确匹配），其序号为 0
+    pub anchor: String,
+    /// 新文件名模板（不含扩展名），支持以下占位符：
+    /// - `{offset}`: 相对锚点的序号，如 "-2"、"0"、"+1"
+    /// - `{name}`: 原文件名去掉扩展名的部分
+    ///
+    /// `{{` 和 `}}` 表示字面的花括号
+    pub offset_format: String,
+}
+
+fn render(template: &str, offset: i64, stem: &str) -> Result<String, RuleError> {
+    render_template(template, |token| match token {
+        "offset" if offset > 0 => Ok(format!("+{}", offset)),
+        "offset" => Ok(offset.to_string()),
+        "name" => Ok(stem.to_string()),
+        _ => Err(RuleError::UnknownToken(token.to_string())),
+    })
+}
+
+/// 按文件名排序后，以锚点文件为 0 计算其他文件的相对序号。
+/// 找不到锚点时所有文件保持原样
+pub fn relative_number(
+    names: &[String],
+    rule: RelativeNumberRule,
+) -> Result<Vec<String>, RuleError> {
+    let mut order: Vec<usize> = (0..names.len()).collect();
+    order.sort_by(|&a, &b| names[a].cmp(&names[b]));
+
+    let stems: Vec<(String, String)> = names.iter().map(|n| split_name(n, true)).collect();
+    let Some(anchor_rank) = order.iter().position(|&i| stems[i].0 == rule.anchor) else {
+        return Ok(names.to_vec());
+    };
+
+    let mut results = names.to_vec();
+    for (rank, &index) in order.iter().enumerate() {
+        let offset = rank as i64 - anchor_rank as i64;
+        let (stem, extension) = &stems[index];
+        results[index] = format!(
+            "{}{}",
+            render(&rule.offset_format, offset, stem)?,
+            extension
+        );
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_relative_number() {
+        let rule = RelativeNumberRule {
+            anchor: "page_c".to_string(),
+            offset_format: "{name} ({offset})".to_string(),
+        };
+        let input = names(&[
+            "page_e.png",
+            "page_a.png",
+            "page_c.png",
+            "page_d.png",
+            "page_b.png",
+        ]);
+
+        let result = relative_number(&input, rule).unwrap();
+        assert_eq!(
+            result,
+            names(&[
+                "page_e (+2).png",
+                "page_a (-2).png",
+                "page_c (0).png",
+                "page_d (+1).png",
+                "page_b (-1).png",
+            ])
+        );
+    }
+
+    #[test]
+    fn test_relative_number_missing_anchor() {
+        let rule = RelativeNumberRule {
+            anchor: "page_z".to_string(),
+            offset_format: "{offset}".to_string(),
+        };
+        let input = names(&["page_a.png", "page_b.png"]);
+
+        let result = relative_number(&input, rule).unwrap();
+        assert_eq!(result, input);
+    }
+
+    #[test]
+    fn test_relative_number_unknown_token() {
+        let rule = RelativeNumberRule {
+            anchor: "a".to_string(),
+            offset_format: "{index}".to_string(),
+        };
+
+        let result = relative_number(&names(&["a.txt"]), rule);
+        assert!(matches!(result, Err(RuleError::UnknownToken(t)) if t == "index"));
+    }
+
+    #[test]
+    fn test_relative_number_template_braces() {
+        let rule = |offset_format: &str| RelativeNumberRule {
+            anchor: "a".to_string(),
+            offset_format: offset_format.to_string(),
+        };
+        let input = names(&["a.txt", "b.txt"]);
+
+        let result = relative_number(&input, rule("{name}{{{offset}}}")).unwrap();
+        assert_eq!(result, names(&["a{0}.txt", "b{+1}.txt"]));
+
+        let result = relative_number(&input, rule("{name}_{offset"));
+        assert!(matches!(result, Err(RuleError::UnterminatedToken(_))));
+    }
+}
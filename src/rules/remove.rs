@@ -1,17 +1,18 @@
+use super::common::split_name;
 use regex::RegexBuilder;
 use serde::{Deserialize, Serialize};
-use std::path::Path;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub enum RemovePosition {
     /// 删除所有出现的文本
+    #[default]
     All,
     /// 删除第一个出现的文本
     First,
     /// 删除最后一个出现的文本
     Last,
 }
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct RemoveRule {
     /// 要移除的文本
     pub text: String,
@@ -21,6 +22,104 @@ pub struct RemoveRule {
     pub case_sensitive: bool,
     /// 忽略扩展名
     pub ignore_extension: bool,
+    /// 不区分大小写时使用完整的 Unicode 大小写折叠进行匹配（如 "ß" 匹配 "SS"），
+    /// 默认使用正则的简单大小写折叠
+    #[serde(default)]
+    pub unicode_case_fold: bool,
+    /// 只删除独立的单词，如删除 "cat" 时不影响 "category"
+    #[serde(default)]
+    pub whole_word: bool,
+    /// 最多删除的匹配数，从前往后计；None 表示不限制
+    #[serde(default)]
+    pub max_count: Option<usize>,
+}
+
+/// 完整 Unicode 大小写折叠。
+/// "İ" 折叠后为 "i" + U+0307（上点），这里把紧跟在 "i" 后的上点去掉，
+/// 使 "İstanbul" 能匹配到 "istanbul"
+fn case_fold(text: &str) -> String {
+    caseless::default_case_fold_str(text).replace("i\u{307}", "i")
+}
+
+/// 在 haystack 中按大小写折叠查找所有不重叠的 needle，返回字节区间
+fn find_folded(haystack: &str, needle: &str) -> Vec<(usize, usize)> {
+    let folded_needle = case_fold(needle);
+    let boundaries: Vec<usize> = haystack
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(haystack.len()))
+        .collect();
+
+    let mut spans = Vec::new();
+    let mut start_at = 0;
+    while start_at < boundaries.len() {
+        let start = boundaries[start_at];
+        let mut matched = None;
+        for (end_at, &end) in boundaries.iter().enumerate().skip(start_at + 1) {
+            let folded = case_fold(&haystack[start..end]);
+            if folded == folded_needle {
+                matched = Some((end_at, end));
+                break;
+            }
+            if !folded_needle.starts_with(&folded) {
+                break;
+            }
+        }
+        match matched {
+            Some((end_at, end)) => {
+                spans.push((start, end));
+                start_at = end_at;
+            }
+            None => start_at += 1,
+        }
+    }
+    spans
+}
+
+/// 按规则找出 name 中所有不重叠的匹配，返回按位置排序的字节区间。
+/// whole_word 时要求匹配前后都不是单词字符（字母、数字和下划线），
+/// 三种匹配方式使用同一个判断，因此首尾是标点的文本（如 "(1)"）也能按单词删除
+fn find_spans(name: &str, rule: &RemoveRule) -> Vec<(usize, usize)> {
+    let regex = if rule.case_sensitive || rule.unicode_case_fold {
+        None
+    } else {
+        match RegexBuilder::new(&regex::escape(&rule.text))
+            .case_insensitive(true)
+            .build()
+        {
+            Ok(re) => Some(re),
+            // 如果 regex 构建失败，保持原样
+            Err(_) => return Vec::new(),
+        }
+    };
+    // 从 pos 开始查找下一个匹配
+    let find_at = |pos: usize| -> Option<(usize, usize)> {
+        if rule.case_sensitive {
+            let start = pos + name[pos..].find(&rule.text)?;
+            Some((start, start + rule.text.len()))
+        } else if let Some(re) = &regex {
+            re.find_at(name, pos).map(|m| (m.start(), m.end()))
+        } else {
+            let (start, end) = *find_folded(&name[pos..], &rule.text).first()?;
+            Some((pos + start, pos + end))
+        }
+    };
+
+    let is_word_char = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+    let mut spans = Vec::new();
+    let mut pos = 0;
+    while let Some((start, end)) = find_at(pos) {
+        let before = name[..start].chars().next_back();
+        let after = name[end..].chars().next();
+        if rule.whole_word && (is_word_char(before) || is_word_char(after)) {
+            // 从下一个字符重新查找，以免漏掉从匹配内部开始的单词
+            pos = start + name[start..].chars().next().map_or(1, char::len_utf8);
+            continue;
+        }
+        spans.push((start, end));
+        pos = end;
+    }
+    spans
 }
 
 pub fn remove(old_text: &str, rule: RemoveRule) -> String {
@@ -29,36 +128,7 @@ pub fn remove(old_text: &str, rule: RemoveRule) -> String {
     }
 
     // 1. 根据 ignore_extension 拆分 old_text 为 "要处理的部分" 和 "要追加的扩展名"
-    let (name_to_process, extension_to_append) = if rule.ignore_extension {
-        let path_obj = Path::new(old_text);
-
-        // file_stem() 获取文件名中最后一个点之前的部分。
-        // 例如: "archive.tar.gz" -> "archive.tar"
-        //       ".bashrc" -> ".bashrc" (因为没有被识别为传统意义的扩展名)
-        //       "nodot" -> "nodot"
-        //       "" -> None
-        //       "/" -> None
-        let base_name = path_obj
-            .file_stem()
-            .map(|s| s.to_string_lossy().into_owned())
-            .unwrap_or_else(|| old_text.to_string()); // 如果没有 stem (如 "" 或 "/"), 则处理整个 old_text
-
-        // 只有当 Path 对象能同时识别出 stem 和 extension 时，我们才分离扩展名
-        // 例如，对于 ".bashrc"，file_stem() 是 ".bashrc"，extension() 是 None。
-        // 我们不希望将其错误地拆分为 name="" 和 ext=".bashrc"
-        let ext_suffix = if path_obj.file_stem().is_some() && path_obj.extension().is_some() {
-            path_obj
-                .extension()
-                .map(|e| format!(".{}", e.to_string_lossy()))
-                .unwrap_or_default() // 理论上如果外层条件满足，这里总是 Some
-        } else {
-            String::new() // 没有可分离的扩展名，或者不应分离
-        };
-        (base_name, ext_suffix)
-    } else {
-        // 不忽略扩展名，则整个 old_text 都是要处理的部分
-        (old_text.to_string(), String::new())
-    };
+    let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
 
     // 如果要处理的部分是空的，并且要移除的文本非空，则无法移除。
     // 例如：old_text="", rule.text="a" -> ""
@@ -68,32 +138,22 @@ pub fn remove(old_text: &str, rule: RemoveRule) -> String {
         return extension_to_append; // 通常是返回空字符串
     }
 
-    // 2. 在 "要处理的部分" (name_to_process) 上执行移除操作
-    let mut final_processed_name_part = name_to_process.clone(); // 克隆一份用于修改
+    // 2. 在 "要处理的部分" (name_to_process) 上找出所有匹配，按位置和数量筛选后移除
+    let spans = find_spans(&name_to_process, &rule);
+    let selected: Vec<(usize, usize)> = match rule.remove_position {
+        RemovePosition::All => spans,
+        RemovePosition::First => spans.first().copied().into_iter().collect(),
+        RemovePosition::Last => spans.last().copied().into_iter().collect(),
+    };
+    let limit = rule.max_count.unwrap_or(usize::MAX);
 
-    match rule.remove_position {
-        RemovePosition::First => {
-            todo!()
-        }
-        RemovePosition::Last => {
-            todo!()
-        }
-        RemovePosition::All => {
-            // 移除所有匹配项
-            if rule.case_sensitive {
-                final_processed_name_part = name_to_process.replace(&rule.text, "");
-            } else {
-                let escaped_text = regex::escape(&rule.text);
-                if let Ok(re) = RegexBuilder::new(&escaped_text)
-                    .case_insensitive(true)
-                    .build()
-                {
-                    final_processed_name_part = re.replace_all(&name_to_process, "").into_owned();
-                }
-                // 如果 regex 构建失败，final_processed_name_part 保持原样
-            }
-        }
+    let mut final_processed_name_part = String::new();
+    let mut last = 0;
+    for (start, end) in selected.into_iter().take(limit) {
+        final_processed_name_part.push_str(&name_to_process[last..start]);
+        last = end;
     }
+    final_processed_name_part.push_str(&name_to_process[last..]);
 
     // 3. 将处理后的部分与之前分离的扩展名（如果适用）重新组合
     format!("{}{}", final_processed_name_part, extension_to_append)
@@ -127,6 +187,7 @@ mod tests {
             remove_position: RemovePosition::All,
             case_sensitive: true,
             ignore_extension: true,
+            ..Default::default()
         };
 
         let result = remove(input, rule);
@@ -149,6 +210,7 @@ mod tests {
             remove_position: RemovePosition::All,
             case_sensitive: true,
             ignore_extension: false,
+            ..Default::default()
         };
 
         let result = remove(input, rule);
@@ -171,6 +233,7 @@ mod tests {
             remove_position: RemovePosition::All,
             case_sensitive: false,
             ignore_extension: false,
+            ..Default::default()
         };
 
         let result = remove(input, rule);
@@ -193,9 +256,166 @@ mod tests {
             remove_position: RemovePosition::All,
             case_sensitive: false,
             ignore_extension: true,
+            ..Default::default()
+        };
+
+        let result = remove(input, rule);
+        assert_eq!(result, expected);
+    }
+
+    // 测试样例：完整 Unicode 大小写折叠（土耳其语 İ、希腊语词尾 σ/ς、德语 ß）
+    #[rstest]
+    #[case("İstanbul trip.jpg", "istanbul ", "trip.jpg")]
+    #[case("ISTANBUL trip.jpg", "İstanbul ", "trip.jpg")]
+    #[case("ΟΔΟΣ map.png", "οδος ", "map.png")]
+    #[case("οδοσ map.png", "ΟΔΟΣ ", "map.png")]
+    #[case("Straße 1.txt", "STRASSE ", "1.txt")]
+    #[case("street 1.txt", "STRASSE ", "street 1.txt")]
+    fn test_remove_all_unicode_case_fold(
+        #[case] input: &str,
+        #[case] text: &str,
+        #[case] expected: &str,
+    ) {
+        let rule = RemoveRule {
+            text: text.to_string(),
+            remove_position: RemovePosition::All,
+            case_sensitive: false,
+            ignore_extension: true,
+            unicode_case_fold: true,
+            ..Default::default()
         };
 
         let result = remove(input, rule);
         assert_eq!(result, expected);
     }
+
+    // 测试样例：默认的正则路径不做完整折叠，土耳其语 İ 与 ß 不会匹配
+    #[rstest]
+    #[case("İstanbul trip.jpg", "istanbul ")]
+    #[case("Straße 1.txt", "STRASSE ")]
+    fn test_remove_all_regex_case_insensitive_without_fold(
+        #[case] input: &str,
+        #[case] text: &str,
+    ) {
+        let rule = RemoveRule {
+            text: text.to_string(),
+            remove_position: RemovePosition::All,
+            case_sensitive: false,
+            ignore_extension: true,
+            ..Default::default()
+        };
+
+        let result = remove(input, rule);
+        assert_eq!(result, input);
+    }
+
+    // 测试样例：只删除独立的单词，区分与不区分大小写
+    #[rstest]
+    #[case("the cat sat.txt", "cat", true, "the  sat.txt")]
+    #[case("category.txt", "cat", true, "category.txt")]
+    #[case("cat_category.txt", "cat", true, "cat_category.txt")]
+    #[case("Cat-nap.txt", "cat", false, "-nap.txt")]
+    #[case("the CAT sat.txt", "cat", true, "the CAT sat.txt")]
+    #[case("the c.a.t sat.txt", "c.a.t", true, "the  sat.txt")]
+    fn test_remove_all_whole_word(
+        #[case] input: &str,
+        #[case] text: &str,
+        #[case] case_sensitive: bool,
+        #[case] expected: &str,
+    ) {
+        let rule = RemoveRule {
+            text: text.to_string(),
+            remove_position: RemovePosition::All,
+            case_sensitive,
+            ignore_extension: true,
+            whole_word: true,
+            ..Default::default()
+        };
+
+        let result = remove(input, rule);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_remove_all_whole_word_unicode_case_fold() {
+        let rule = RemoveRule {
+            text: "STRASSE".to_string(),
+            remove_position: RemovePosition::All,
+            case_sensitive: false,
+            ignore_extension: true,
+            unicode_case_fold: true,
+            whole_word: true,
+            ..Default::default()
+        };
+
+        assert_eq!(remove("Straße 1.txt", rule.clone()), " 1.txt");
+        assert_eq!(remove("Straßenbahn.txt", rule), "Straßenbahn.txt");
+    }
+
+    // 测试样例：删除第一个、最后一个以及最多 N 个匹配
+    #[rstest]
+    #[case("banana", RemovePosition::All, Some(2), true, "bnna")]
+    #[case("banana", RemovePosition::All, Some(0), true, "banana")]
+    #[case("banana", RemovePosition::All, Some(5), true, "bnn")]
+    #[case("bAnAna", RemovePosition::All, Some(2), false, "bnna")]
+    #[case("banana", RemovePosition::First, None, true, "bnana")]
+    #[case("banana", RemovePosition::Last, None, true, "banan")]
+    #[case("BANANA", RemovePosition::Last, None, false, "BANAN")]
+    #[case("banana", RemovePosition::Last, Some(0), true, "banana")]
+    fn test_remove_position_and_max_count(
+        #[case] input: &str,
+        #[case] remove_position: RemovePosition,
+        #[case] max_count: Option<usize>,
+        #[case] case_sensitive: bool,
+        #[case] expected: &str,
+    ) {
+        let rule = RemoveRule {
+            text: "a".to_string(),
+            remove_position,
+            case_sensitive,
+            ignore_extension: true,
+            max_count,
+            ..Default::default()
+        };
+
+        let result = remove(input, rule);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_remove_max_count_unicode_case_fold() {
+        let rule = RemoveRule {
+            text: "ss".to_string(),
+            case_sensitive: false,
+            ignore_extension: true,
+            unicode_case_fold: true,
+            max_count: Some(1),
+            ..Default::default()
+        };
+
+        assert_eq!(remove("ßaSSbß.txt", rule), "aSSbß.txt");
+    }
+
+    // 测试样例：首尾是标点的文本在三种匹配方式下结果一致
+    #[rstest]
+    #[case(true, false)]
+    #[case(false, false)]
+    #[case(false, true)]
+    fn test_remove_whole_word_punctuation_edges(
+        #[case] case_sensitive: bool,
+        #[case] unicode_case_fold: bool,
+    ) {
+        let rule = RemoveRule {
+            text: "(1)".to_string(),
+            case_sensitive,
+            ignore_extension: true,
+            unicode_case_fold,
+            whole_word: true,
+            ..Default::default()
+        };
+
+        assert_eq!(remove("a (1) b.txt", rule.clone()), "a  b.txt");
+        assert_eq!(remove("a(1).txt", rule.clone()), "a(1).txt");
+        assert_eq!(remove("(1)b (1).txt", rule), "(1)b .txt");
+    }
 }
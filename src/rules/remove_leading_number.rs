@@ -0,0 +1,63 @@
+use super::common::split_name;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoveLeadingNumberRule {
+    /// 数字之后一并移除的分隔符
+    pub separators: Vec<char>,
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+    /// 只移除不超过该位数的数字，例如设为 3 可保留 "2023 Report" 中的年份；为 None 时不限制
+    #[serde(default)]
+    pub max_digits: Option<usize>,
+}
+
+/// 移除开头的数字串及其后紧跟的分隔符，例如 "01 - Song.mp3" -> "Song.mp3"。
+/// 只处理开头的数字，文件名中间的数字不受影响；移除后为空时保持原样
+pub fn remove_leading_number(old_text: &str, rule: RemoveLeadingNumberRule) -> String {
+    let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
+
+    let digits = name_to_process
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .count();
+    if digits == 0 || rule.max_digits.is_some_and(|max| digits > max) {
+        return old_text.to_string();
+    }
+
+    let rest = name_to_process[digits..].trim_start_matches(rule.separators.as_slice());
+    if rest.is_empty() {
+        return old_text.to_string();
+    }
+    format!("{}{}", rest, extension_to_append)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：移除开头的曲目编号
+    #[rstest]
+    #[case("01 - Song.mp3", None, "Song.mp3")]
+    #[case("3_Track_9.mp3", None, "Track_9.mp3")]
+    #[case("2023 Report.pdf", None, "Report.pdf")]
+    #[case("2023 Report.pdf", Some(3), "2023 Report.pdf")]
+    #[case("102. Intro.mp3", Some(3), "Intro.mp3")]
+    #[case("Song 01.mp3", None, "Song 01.mp3")]
+    #[case("2023.pdf", None, "2023.pdf")]
+    fn test_remove_leading_number(
+        #[case] input: &str,
+        #[case] max_digits: Option<usize>,
+        #[case] expected: &str,
+    ) {
+        let rule = RemoveLeadingNumberRule {
+            separators: vec![' ', '-', '_', '.'],
+            ignore_extension: true,
+            max_digits,
+        };
+
+        let result = remove_leading_number(input, rule);
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,69 @@
+use super::common::split_name;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoveReleaseGroupRule {
+    /// 发布组标记前的分隔符，通常为 '-'
+    pub delimiter: char,
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+}
+
+/// 形似发布组但实际是集数、分辨率或年份的片段，如 "S01E02"、"1080P"、"4K"、"2021"
+const NOT_A_GROUP: &str = r"^(?:S\d+(?:E\d+)*|E\d+|\d{3,4}[PI]|\d+K|(?:19|20)\d{2})$";
+
+/// 是否像发布组名称：至少两个字符，只包含 ASCII 大写字母和数字，且至少有一个字母，
+/// 如 "RARBG"、"YTS"；"final"、"v2"、"2021" 这样的片段不会被当作发布组，
+/// "S01E02"、"1080P"、"4K" 等集数和画质标记也不会
+fn is_release_group(token: &str) -> bool {
+    let not_a_group = Regex::new(NOT_A_GROUP).expect("标记正则是合法的");
+    token.len() >= 2
+        && !not_a_group.is_match(token)
+        && token
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+        && token.chars().any(|c| c.is_ascii_uppercase())
+}
+
+/// 去掉媒体文件名末尾的 "<分隔符><发布组>"，如 "Movie.2021-RARBG.mkv" -> "Movie.2021.mkv"
+pub fn remove_release_group(old_text: &str, rule: RemoveReleaseGroupRule) -> String {
+    let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
+
+    match name_to_process.rsplit_once(rule.delimiter) {
+        Some((rest, token)) if !rest.is_empty() && is_release_group(token) => {
+            format!("{}{}", rest, extension_to_append)
+        }
+        _ => old_text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：只去掉像发布组的末尾片段
+    #[rstest]
+    #[case("Movie.2021-RARBG.mkv", "Movie.2021.mkv")]
+    #[case("Show.S01E02.1080p-NTB.mp4", "Show.S01E02.1080p.mp4")]
+    #[case("Movie.2021-YTS2.mkv", "Movie.2021.mkv")]
+    #[case("report-final.pdf", "report-final.pdf")]
+    #[case("Movie-2021.mkv", "Movie-2021.mkv")]
+    #[case("-RARBG.mkv", "-RARBG.mkv")]
+    #[case("Movie.2021.mkv", "Movie.2021.mkv")]
+    #[case("Doctor.Who-S01E02.mkv", "Doctor.Who-S01E02.mkv")]
+    #[case("Doctor.Who-S01.mkv", "Doctor.Who-S01.mkv")]
+    #[case("Movie-1080P.mkv", "Movie-1080P.mkv")]
+    #[case("Movie-720P.mkv", "Movie-720P.mkv")]
+    #[case("Movie-4K.mkv", "Movie-4K.mkv")]
+    fn test_remove_release_group(#[case] input: &str, #[case] expected: &str) {
+        let rule = RemoveReleaseGroupRule {
+            delimiter: '-',
+            ignore_extension: true,
+        };
+
+        let result = remove_release_group(input, rule);
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,138 @@
+use super::common::split_name;
+use super::strip_brackets::BracketKind;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// 参与配对检查的括号种类
+const KINDS: [BracketKind; 4] = [
+    BracketKind::Round,
+    BracketKind::Square,
+    BracketKind::Curly,
+    BracketKind::Angle,
+];
+
+/// 不配对的括号的处理方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BracketRepair {
+    /// 删除不配对的括号
+    #[default]
+    Remove,
+    /// 补上缺少的另一半
+    Complete,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RepairBracketsRule {
+    /// 不配对的括号的处理方式
+    pub mode: BracketRepair,
+}
+
+/// 修复其他规则（如裁剪、删除）留下的不配对括号，扩展名保持不变。
+/// 删除模式下去掉孤立的括号，并合并接缝处的空白、去掉首尾空白；
+/// 补全模式下，未闭合的左括号在末尾补上右括号，多余的右括号在开头补上左括号，
+/// 交错的括号如 "(a [b) c" 在外层括号闭合前补上，得到 "(a [b]) c"
+pub fn repair_brackets(old_text: &str, rule: RepairBracketsRule) -> String {
+    let (name_to_process, extension_to_append) = split_name(old_text, true);
+    let chars: Vec<char> = name_to_process.chars().collect();
+
+    let mut orphans = vec![false; chars.len()];
+    // 补全模式下要插入到某个字符之前的括号
+    let mut insert_before: HashMap<usize, String> = HashMap::new();
+    let mut prefix = Vec::new();
+    let mut stack: Vec<(BracketKind, usize)> = Vec::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if let Some(&kind) = KINDS.iter().find(|k| k.open() == c) {
+            stack.push((kind, i));
+        } else if let Some(&kind) = KINDS.iter().find(|k| k.close() == c) {
+            match stack.iter().rposition(|&(k, _)| k == kind) {
+                Some(depth) => {
+                    // 在它之后打开、尚未闭合的括号都是孤立的
+                    for (inner, j) in stack.drain(depth + 1..).rev() {
+                        orphans[j] = true;
+                        insert_before.entry(i).or_default().push(inner.close());
+                    }
+                    stack.pop();
+                }
+                None => {
+                    orphans[i] = true;
+                    prefix.push(kind.open());
+                }
+            }
+        }
+    }
+    // 剩下未闭合的左括号，按从内到外的顺序在末尾补上右括号
+    let mut suffix = String::new();
+    for &(kind, j) in stack.iter().rev() {
+        orphans[j] = true;
+        suffix.push(kind.close());
+    }
+
+    if !orphans.contains(&true) {
+        return old_text.to_string();
+    }
+
+    let repaired = match rule.mode {
+        BracketRepair::Remove => {
+            let kept: String = chars
+                .iter()
+                .zip(&orphans)
+                .filter(|&(_, &orphan)| !orphan)
+                .map(|(&c, _)| c)
+                .collect();
+            kept.split_whitespace().collect::<Vec<_>>().join(" ")
+        }
+        BracketRepair::Complete => {
+            let mut result: String = prefix.iter().rev().collect();
+            for (i, &c) in chars.iter().enumerate() {
+                if let Some(closes) = insert_before.get(&i) {
+                    result.push_str(closes);
+                }
+                result.push(c);
+            }
+            result.push_str(&suffix);
+            result
+        }
+    };
+    format!("{}{}", repaired, extension_to_append)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：删除孤立的括号
+    #[rstest]
+    #[case("Movie ].mkv", "Movie.mkv")]
+    #[case("Movie [.mkv", "Movie.mkv")]
+    #[case("Movie ) [1080p] (.mkv", "Movie [1080p].mkv")]
+    #[case("(a [b) c.txt", "(a b) c.txt")]
+    #[case("a } b > c.txt", "a b c.txt")]
+    #[case("Movie (2021) [1080p].mkv", "Movie (2021) [1080p].mkv")]
+    fn test_repair_brackets_remove(#[case] input: &str, #[case] expected: &str) {
+        let rule = RepairBracketsRule {
+            mode: BracketRepair::Remove,
+        };
+
+        let result = repair_brackets(input, rule);
+        assert_eq!(result, expected);
+    }
+
+    // 测试样例：补上缺少的另一半
+    #[rstest]
+    #[case("Movie [1080p.mkv", "Movie [1080p].mkv")]
+    #[case("1080p] Movie.mkv", "[1080p] Movie.mkv")]
+    #[case("Movie ].mkv", "[Movie ].mkv")]
+    #[case("Movie [.mkv", "Movie [].mkv")]
+    #[case("(a [b) c.txt", "(a [b]) c.txt")]
+    #[case("a] b).txt", "([a] b).txt")]
+    #[case("{Movie (2021.mkv", "{Movie (2021)}.mkv")]
+    fn test_repair_brackets_complete(#[case] input: &str, #[case] expected: &str) {
+        let rule = RepairBracketsRule {
+            mode: BracketRepair::Complete,
+        };
+
+        let result = repair_brackets(input, rule);
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,212 @@
+use super::kana::{KanaDir, KanaRule, kana};
+use pinyin::ToPinyin;
+use serde::{Deserialize, Serialize};
+
+/// 罗马字转写方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RomanizeSystem {
+    /// 汉字转为不带声调的拼音，每个音节首字母大写以保留音节边界，如 "北京" -> "BeiJing"
+    Pinyin,
+    /// 平假名和片假名按平文式（Hepburn）转为罗马字，如 "とうきょう" -> "toukyou"；
+    /// 汉字无法确定读音，保持不变
+    Romaji,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RomanizeRule {
+    /// 转写方式
+    pub system: RomanizeSystem,
+}
+
+/// 单个平假名的罗马字，不是平假名时返回 None
+fn kana_romaji(c: char) -> Option<&'static str> {
+    Some(match c {
+        'あ' | 'ぁ' => "a",
+        'い' | 'ぃ' | 'ゐ' => "i",
+        'う' | 'ぅ' => "u",
+        'え' | 'ぇ' | 'ゑ' => "e",
+        'お' | 'ぉ' | 'を' => "o",
+        'か' => "ka",
+        'き' => "ki",
+        'く' => "ku",
+        'け' => "ke",
+        'こ' => "ko",
+        'が' => "ga",
+        'ぎ' => "gi",
+        'ぐ' => "gu",
+        'げ' => "ge",
+        'ご' => "go",
+        'さ' => "sa",
+        'し' => "shi",
+        'す' => "su",
+        'せ' => "se",
+        'そ' => "so",
+        'ざ' => "za",
+        'じ' | 'ぢ' => "ji",
+        'ず' | 'づ' => "zu",
+        'ぜ' => "ze",
+        'ぞ' => "zo",
+        'た' => "ta",
+        'ち' => "chi",
+        'つ' => "tsu",
+        'て' => "te",
+        'と' => "to",
+        'だ' => "da",
+        'で' => "de",
+        'ど' => "do",
+        'な' => "na",
+        'に' => "ni",
+        'ぬ' => "nu",
+        'ね' => "ne",
+        'の' => "no",
+        'は' => "ha",
+        'ひ' => "hi",
+        'ふ' => "fu",
+        'へ' => "he",
+        'ほ' => "ho",
+        'ば' => "ba",
+        'び' => "bi",
+        'ぶ' => "bu",
+        'べ' => "be",
+        'ぼ' => "bo",
+        'ぱ' => "pa",
+        'ぴ' => "pi",
+        'ぷ' => "pu",
+        'ぺ' => "pe",
+        'ぽ' => "po",
+        'ま' => "ma",
+        'み' => "mi",
+        'む' => "mu",
+        'め' => "me",
+        'も' => "mo",
+        'や' | 'ゃ' => "ya",
+        'ゆ' | 'ゅ' => "yu",
+        'よ' | 'ょ' => "yo",
+        'ら' => "ra",
+        'り' => "ri",
+        'る' => "ru",
+        'れ' => "re",
+        'ろ' => "ro",
+        'わ' => "wa",
+        'ん' => "n",
+        'ゔ' => "vu",
+        _ => return None,
+    })
+}
+
+fn romaji(text: &str) -> String {
+    // 先把片假名统一为平假名，这样只需要一张表
+    let hiragana = kana(
+        text,
+        KanaRule {
+            direction: KanaDir::ToHiragana,
+        },
+    );
+    let chars: Vec<char> = hiragana.chars().collect();
+    let mut result = String::new();
+    let mut geminate = false; // 前面是促音 "っ"，下一个辅音要重复
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let Some(mut syllable) = kana_romaji(c).map(str::to_string) else {
+            match c {
+                'っ' => geminate = true,
+                // 长音符重复前一个元音，如 "コーヒー" -> "koohii"
+                'ー' => match result.chars().next_back() {
+                    Some(v) if "aiueo".contains(v) => result.push(v),
+                    _ => result.push(c),
+                },
+                _ => result.push(c),
+            }
+            i += 1;
+            continue;
+        };
+        // 拗音：き + ゃ -> kya，し + ゃ -> sha
+        if let Some(small @ ('ゃ' | 'ゅ' | 'ょ')) = chars.get(i + 1).copied()
+            && syllable.len() > 1
+            && syllable.ends_with('i')
+        {
+            syllable.pop();
+            let vowel = &kana_romaji(small).unwrap()[1..];
+            if !matches!(syllable.as_str(), "sh" | "ch" | "j") {
+                syllable.push('y');
+            }
+            syllable.push_str(vowel);
+            i += 1;
+        }
+        if geminate {
+            // 促音在 "ch" 前写作 "t"，如 "まっちゃ" -> "matcha"
+            match syllable.chars().next() {
+                Some('c') => result.push('t'),
+                Some(first) if !"aiueon".contains(first) => result.push(first),
+                _ => {}
+            }
+            geminate = false;
+        }
+        result.push_str(&syllable);
+        i += 1;
+    }
+    result
+}
+
+fn pinyin(text: &str) -> String {
+    let mut result = String::new();
+    for (c, pinyin) in text.chars().zip(text.to_pinyin()) {
+        match pinyin {
+            Some(pinyin) => {
+                let mut letters = pinyin.plain().chars();
+                if let Some(first) = letters.next() {
+                    result.extend(first.to_uppercase());
+                    result.extend(letters);
+                }
+            }
+            None => result.push(c),
+        }
+    }
+    result
+}
+
+/// 把中文转为拼音，或把日文假名转为罗马字，其他字符保持不变
+pub fn romanize(old_text: &str, rule: RomanizeRule) -> String {
+    match rule.system {
+        RomanizeSystem::Pinyin => pinyin(old_text),
+        RomanizeSystem::Romaji => romaji(old_text),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：汉字转拼音，非中文字符不变
+    #[rstest]
+    #[case("北京.txt", "BeiJing.txt")]
+    #[case("张三_report.doc", "ZhangSan_report.doc")]
+    #[case("photo 01.jpg", "photo 01.jpg")]
+    fn test_romanize_pinyin(#[case] input: &str, #[case] expected: &str) {
+        let rule = RomanizeRule {
+            system: RomanizeSystem::Pinyin,
+        };
+
+        let result = romanize(input, rule);
+        assert_eq!(result, expected);
+    }
+
+    // 测试样例：拗音、促音、长音符，以及保持不变的汉字
+    #[rstest]
+    #[case("とうきょう.txt", "toukyou.txt")]
+    #[case("しゃしん.jpg", "shashin.jpg")]
+    #[case("きって", "kitte")]
+    #[case("まっちゃ", "matcha")]
+    #[case("コーヒー", "koohii")]
+    #[case("日本カメラ.png", "日本kamera.png")]
+    fn test_romanize_romaji(#[case] input: &str, #[case] expected: &str) {
+        let rule = RomanizeRule {
+            system: RomanizeSystem::Romaji,
+        };
+
+        let result = romanize(input, rule);
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,88 @@
+use super::common::{InsertPosition, insert_text};
+use super::error::RuleError;
+use serde::{Deserialize, Serialize};
+
+/// base-62 字符表：数字、小写字母、大写字母
+const ALPHABET: &[u8; 62] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// 短 id 与原文件名之间的分隔符
+const SEPARATOR: &str = "_";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShortIdRule {
+    /// id 的最小长度，不足时左侧补 '0'
+    pub length: usize,
+    /// 插入的位置
+    pub position: InsertPosition,
+}
+
+/// 把下标编码为 base-62 字符串，至少 length 位
+fn encode(mut index: usize, length: usize) -> String {
+    let mut digits = Vec::new();
+    while index > 0 {
+        digits.push(ALPHABET[index % 62]);
+        index /= 62;
+    }
+    while digits.len() < length.max(1) {
+        digits.push(b'0');
+    }
+    digits.iter().rev().map(|&b| b as char).collect()
+}
+
+/// 按文件在批次中的下标生成 base-62 短 id 并插入文件名，扩展名保持不变。
+/// id 只取决于顺序而不是随机数，同样的顺序多次运行结果一致；
+/// 批次太大以至于超出 length 位时 id 会变长，而不是截断，以保证唯一
+pub fn short_id(names: &[String], rule: ShortIdRule) -> Result<Vec<String>, RuleError> {
+    Ok(names
+        .iter()
+        .enumerate()
+        .map(|(index, name)| {
+            insert_text(
+                name,
+                &encode(index, rule.length),
+                rule.position,
+                SEPARATOR,
+                true,
+            )
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：第 0 个、第 61 个及进位后的 id
+    #[rstest]
+    #[case(0, 4, "0000")]
+    #[case(61, 4, "000Z")]
+    #[case(62, 4, "0010")]
+    #[case(61, 0, "Z")]
+    #[case(62 * 62, 2, "100")]
+    fn test_encode(#[case] index: usize, #[case] length: usize, #[case] expected: &str) {
+        assert_eq!(encode(index, length), expected);
+    }
+
+    #[test]
+    fn test_short_id() {
+        let names: Vec<String> = (0..62).map(|i| format!("file{}.txt", i)).collect();
+        let rule = ShortIdRule {
+            length: 3,
+            position: InsertPosition::Suffix,
+        };
+
+        let result = short_id(&names, rule.clone()).unwrap();
+        assert_eq!(result[0], "file0_000.txt");
+        assert_eq!(result[61], "file61_00Z.txt");
+        // 同样的顺序再次运行得到相同结果
+        assert_eq!(short_id(&names, rule).unwrap(), result);
+
+        let rule = ShortIdRule {
+            length: 2,
+            position: InsertPosition::Prefix,
+        };
+        let result = short_id(&names[..2], rule).unwrap();
+        assert_eq!(result, vec!["00_file0.txt", "01_file1.txt"]);
+    }
+}
@@ -0,0 +1,119 @@
+use super::common::split_name;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShortenRule {
+    /// 主干允许的最大字符数，至少按 1 计算
+    pub max_len: usize,
+    /// 哈希后缀的长度（十六进制字符数，1 到 16）
+    pub hash_len: usize,
+}
+
+/// FNV-1a 64 位哈希。结果只取决于输入字节，跨版本、跨平台稳定
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+/// 主干超过 max_len 时截断，并追加由完整主干计算的哈希，
+/// 使前缀相同的长文件名截断后依然互不相同
+pub fn shorten(old_text: &str, rule: ShortenRule) -> String {
+    let (stem, extension) = split_name(old_text, true);
+    // 主干不能为空；没有哈希时截断结果无法区分，也不应留下多余的分隔符
+    let max_len = rule.max_len.max(1);
+    if stem.chars().count() <= max_len {
+        return old_text.to_string();
+    }
+
+    let hash_len = rule.hash_len.clamp(1, 16);
+    let hash: String = format!("{:016x}", fnv1a(&stem))
+        .chars()
+        .take(hash_len)
+        .collect();
+
+    // 连哈希和分隔符都放不下时，只保留截断的哈希
+    if max_len <= hash_len + 1 {
+        let hash: String = hash.chars().take(max_len).collect();
+        return format!("{}{}", hash, extension);
+    }
+
+    let kept: String = stem.chars().take(max_len - hash_len - 1).collect();
+    format!("{}_{}{}", kept, hash, extension)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：短文件名保持不变，长文件名截断到 max_len
+    #[rstest]
+    #[case("short.txt", 20, 6, "short.txt")]
+    #[case("exactly-ten.txt", 11, 6, "exactly-ten.txt")]
+    fn test_shorten_untouched(
+        #[case] input: &str,
+        #[case] max_len: usize,
+        #[case] hash_len: usize,
+        #[case] expected: &str,
+    ) {
+        let result = shorten(input, ShortenRule { max_len, hash_len });
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_shorten_keeps_long_names_distinct() {
+        let rule = ShortenRule {
+            max_len: 16,
+            hash_len: 6,
+        };
+        let a = shorten("quarterly report for the sales team.pdf", rule.clone());
+        let b = shorten("quarterly report for the marketing team.pdf", rule.clone());
+
+        assert_ne!(a, b);
+        assert!(a.starts_with("quarterly_"));
+        assert!(a.ends_with(".pdf"));
+        assert_eq!(a.trim_end_matches(".pdf").chars().count(), 16);
+        // 结果是确定的
+        assert_eq!(a, shorten("quarterly report for the sales team.pdf", rule));
+    }
+
+    #[test]
+    fn test_shorten_max_len_smaller_than_hash() {
+        let rule = ShortenRule {
+            max_len: 4,
+            hash_len: 6,
+        };
+
+        let result = shorten("a very long name.txt", rule);
+        assert_eq!(result.trim_end_matches(".txt").chars().count(), 4);
+    }
+
+    #[test]
+    fn test_shorten_zero_hash_len() {
+        let rule = ShortenRule {
+            max_len: 8,
+            hash_len: 0,
+        };
+        let a = shorten("a very long name.txt", rule.clone());
+        let b = shorten("a very long game.txt", rule);
+
+        assert_ne!(a, b);
+        assert!(a.starts_with("a very_"));
+        assert!(!a.trim_end_matches(".txt").ends_with('_'));
+        assert_eq!(a.trim_end_matches(".txt").chars().count(), 8);
+    }
+
+    #[test]
+    fn test_shorten_zero_max_len() {
+        let rule = ShortenRule {
+            max_len: 0,
+            hash_len: 6,
+        };
+
+        let result = shorten("a very long name.txt", rule);
+        let stem = result.trim_end_matches(".txt");
+        assert_eq!(stem.chars().count(), 1);
+        assert!(!stem.contains('_'));
+    }
+}
@@ -0,0 +1,80 @@
+use super::common::split_name;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SidecarRule {
+    /// 伴随文件的扩展名（如 "meta"，可带或不带开头的点）
+    pub extension: String,
+    /// 忽略扩展名：为 true 时只替换主干并保留原扩展名，否则替换整个文件名
+    pub ignore_extension: bool,
+}
+
+/// 读取与文件同名的伴随文件（如 photo.jpg 对应 photo.meta），
+/// 用其第一行（去除首尾空白）作为新名称。伴随文件缺失、第一行为空，
+/// 或第一行包含路径分隔符、为 "." 或 ".."（会把文件移出所在目录）时保持原名
+pub fn sidecar(path: &Path, rule: SidecarRule) -> String {
+    let old_text = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    let sidecar_path = path.with_extension(rule.extension.trim_start_matches('.'));
+    if sidecar_path == path {
+        return old_text; // 伴随文件就是自身
+    }
+    let Ok(content) = fs::read_to_string(&sidecar_path) else {
+        return old_text;
+    };
+    let new_name = content.lines().next().unwrap_or_default().trim();
+    if new_name.is_empty() || new_name.contains(['/', '\\']) || matches!(new_name, "." | "..") {
+        return old_text;
+    }
+
+    let (_, extension_to_append) = split_name(&old_text, rule.ignore_extension);
+    format!("{}{}", new_name, extension_to_append)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：使用伴随文件第一行重命名
+    #[rstest]
+    #[case(
+        "photo.jpg",
+        Some("  Beach Sunset \nsecond line\n"),
+        true,
+        "Beach Sunset.jpg"
+    )]
+    #[case("photo.jpg", Some("Beach Sunset.png"), false, "Beach Sunset.png")]
+    #[case("photo.jpg", Some("\n"), true, "photo.jpg")]
+    #[case("photo.jpg", None, true, "photo.jpg")]
+    #[case("photo.jpg", Some("../../x"), true, "photo.jpg")]
+    #[case("photo.jpg", Some("a/b"), false, "photo.jpg")]
+    #[case("photo.jpg", Some("a\\b"), true, "photo.jpg")]
+    #[case("photo.jpg", Some(".."), false, "photo.jpg")]
+    fn test_sidecar(
+        #[case] file_name: &str,
+        #[case] sidecar_content: Option<&str>,
+        #[case] ignore_extension: bool,
+        #[case] expected: &str,
+    ) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(file_name);
+        fs::write(&path, "").unwrap();
+        if let Some(content) = sidecar_content {
+            fs::write(path.with_extension("meta"), content).unwrap();
+        }
+
+        let rule = SidecarRule {
+            extension: ".meta".to_string(),
+            ignore_extension,
+        };
+
+        let result = sidecar(&path, rule);
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,128 @@
+use super::common::{format_size, split_name};
+use chrono::NaiveDate;
+use regex::{Captures, Regex};
+use serde::{Deserialize, Serialize};
+
+/// 二进制单位及其字节数
+const UNITS: [(&str, u64); 6] = [
+    ("B", 1),
+    ("KiB", 1 << 10),
+    ("MiB", 1 << 20),
+    ("GiB", 1 << 30),
+    ("TiB", 1 << 40),
+    ("PiB", 1 << 50),
+];
+
+/// 转换方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SizeDir {
+    /// 字节数 -> 可读形式，如 "1048576" -> "1MiB"
+    ToHuman,
+    /// 可读形式 -> 字节数，如 "1.5KiB" -> "1536"
+    ToBytes,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SizeFormatRule {
+    /// 转换方向
+    pub direction: SizeDir,
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+}
+
+/// 只有像字节数的数字才会被转换：至少 5 位（排除年份和普通序号）、不以 0 开头，
+/// 并且不是 YYYYMMDD 形式的日期
+fn is_plausible_bytes(digits: &str) -> bool {
+    digits.len() >= 5
+        && !digits.starts_with('0')
+        && digits.parse::<u64>().is_ok()
+        && !(digits.len() == 8 && NaiveDate::parse_from_str(digits, "%Y%m%d").is_ok())
+}
+
+/// 单位 B 只在大写、数值为至少两位的整数时识别，
+/// 避免把 "part 2b"、"disc 1B" 这类编号当成大小
+fn to_bytes(caps: &Captures) -> Option<String> {
+    if &caps[2] == "B" && (caps[1].len() < 2 || caps[1].contains('.')) {
+        return None;
+    }
+    let value: f64 = caps[1].parse().ok()?;
+    let (_, factor) = UNITS
+        .iter()
+        .find(|(unit, _)| unit.eq_ignore_ascii_case(&caps[2]))?;
+    Some(((value * *factor as f64).round() as u64).to_string())
+}
+
+/// 在字节数和可读的大小（二进制单位，如 KiB、MiB）之间转换文件名中的大小。
+/// 前后紧挨字母或数字的片段不会被转换，如 "v1048576" 中的数字
+pub fn size_format(old_text: &str, rule: SizeFormatRule) -> String {
+    let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
+
+    let re = match rule.direction {
+        SizeDir::ToHuman => Regex::new(r"\d+").unwrap(),
+        SizeDir::ToBytes => Regex::new(r"(\d+(?:\.\d+)?) ?((?i:KiB|MiB|GiB|TiB|PiB)|B)").unwrap(),
+    };
+    let standalone = |caps: &Captures| {
+        let m = caps.get(0).unwrap();
+        let before = name_to_process[..m.start()].chars().next_back();
+        let after = name_to_process[m.end()..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    };
+    let converted = re.replace_all(&name_to_process, |caps: &Captures| {
+        let replacement = match rule.direction {
+            _ if !standalone(caps) => None,
+            SizeDir::ToHuman if is_plausible_bytes(&caps[0]) => {
+                Some(format_size(caps[0].parse().unwrap()))
+            }
+            SizeDir::ToHuman => None,
+            SizeDir::ToBytes => to_bytes(caps),
+        };
+        replacement.unwrap_or_else(|| caps[0].to_string())
+    });
+    format!("{}{}", converted, extension_to_append)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：字节数 -> 可读形式，排除年份、日期和嵌在单词中的数字
+    #[rstest]
+    #[case("dump 1048576.bin", "dump 1MiB.bin")]
+    #[case("disk_1572864.img", "disk_1.5MiB.img")]
+    #[case("report 2023.pdf", "report 2023.pdf")]
+    #[case("IMG_20230105.jpg", "IMG_20230105.jpg")]
+    #[case("v1048576.bin", "v1048576.bin")]
+    #[case("id 0012345.txt", "id 0012345.txt")]
+    fn test_size_format_to_human(#[case] input: &str, #[case] expected: &str) {
+        let rule = SizeFormatRule {
+            direction: SizeDir::ToHuman,
+            ignore_extension: true,
+        };
+
+        let result = size_format(input, rule);
+        assert_eq!(result, expected);
+    }
+
+    // 测试样例：可读形式 -> 字节数
+    #[rstest]
+    #[case("dump 1MiB.bin", "dump 1048576.bin")]
+    #[case("disk_1.5 MiB.img", "disk_1572864.img")]
+    #[case("cache 512kib.dat", "cache 524288.dat")]
+    #[case("tiny 10B.txt", "tiny 10.txt")]
+    #[case("model 7MiBx.bin", "model 7MiBx.bin")]
+    #[case("notes 1MB.txt", "notes 1MB.txt")]
+    #[case("part 2b.txt", "part 2b.txt")]
+    #[case("disc 1B.iso", "disc 1B.iso")]
+    #[case("disc 1B", "disc 1B")]
+    #[case("tiny 10b.txt", "tiny 10b.txt")]
+    fn test_size_format_to_bytes(#[case] input: &str, #[case] expected: &str) {
+        let rule = SizeFormatRule {
+            direction: SizeDir::ToBytes,
+            ignore_extension: true,
+        };
+
+        let result = size_format(input, rule);
+        assert_eq!(result, expected);
+    }
+}
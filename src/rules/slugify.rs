@@ -0,0 +1,73 @@
+use super::common::{remove_accents, split_name};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlugifyRule {
+    /// 替换非字母数字字符的分隔符
+    pub separator: char,
+    /// 转为小写
+    pub lowercase: bool,
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+}
+
+/// 生成可以安全用于 URL 的文件名：去掉重音，把非 ASCII 字母数字的连续字符替换为一个分隔符，
+/// 并去掉首尾的分隔符，如 "Héllo World! (v2).txt" -> "hello-world-v2.txt"
+pub fn slugify(old_text: &str, rule: SlugifyRule) -> String {
+    let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
+
+    let mut slug = String::new();
+    let mut pending_separator = false;
+    for c in remove_accents(&name_to_process).chars() {
+        if c.is_ascii_alphanumeric() {
+            if pending_separator && !slug.is_empty() {
+                slug.push(rule.separator);
+            }
+            pending_separator = false;
+            slug.push(if rule.lowercase {
+                c.to_ascii_lowercase()
+            } else {
+                c
+            });
+        } else {
+            pending_separator = true;
+        }
+    }
+
+    let extension = if rule.lowercase {
+        extension_to_append.to_lowercase()
+    } else {
+        extension_to_append
+    };
+    format!("{}{}", slug, extension)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：各种杂乱的输入
+    #[rstest]
+    #[case("Héllo World! (v2).txt", '-', true, "hello-world-v2.txt")]
+    #[case("  --Already--slugged--  .md", '-', true, "already-slugged.md")]
+    #[case("Crème Brûlée & Straße.JPG", '_', true, "creme_brulee_strasse.jpg")]
+    #[case("Crème Brûlée & Straße.JPG", '_', false, "Creme_Brulee_Strasse.JPG")]
+    #[case("Ærøskøbing 2023.png", '-', true, "aeroskobing-2023.png")]
+    #[case("中文 name.txt", '-', true, "name.txt")]
+    fn test_slugify(
+        #[case] input: &str,
+        #[case] separator: char,
+        #[case] lowercase: bool,
+        #[case] expected: &str,
+    ) {
+        let rule = SlugifyRule {
+            separator,
+            lowercase,
+            ignore_extension: true,
+        };
+
+        let result = slugify(input, rule);
+        assert_eq!(result, expected);
+    }
+}
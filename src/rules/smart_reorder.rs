@@ -0,0 +1,150 @@
+use super::common::split_name;
+use serde::{Deserialize, Serialize};
+
+/// 文件名片段的类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TokenType {
+    /// 1900–2099 之间的年份，可以带括号，如 "2021"、"(2021)"
+    Year,
+    /// 其他纯数字，如集数 "03"
+    Number,
+    /// 普通文字
+    Text,
+    /// 括号中的标记，如 "[1080p]"、"(Director's Cut)"
+    Tag,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmartReorderRule {
+    /// 各类片段的排列顺序；没有列出的类型按原来的相对顺序排在最后
+    pub order: Vec<TokenType>,
+}
+
+/// 按空白切分片段，括号内的内容（可以包含空格）作为一个片段
+fn tokenize(text: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        let mut token = String::new();
+        let close = match c {
+            '(' => Some(')'),
+            '[' => Some(']'),
+            _ => None,
+        };
+        match close {
+            Some(close) => {
+                for c in chars.by_ref() {
+                    token.push(c);
+                    if c == close {
+                        break;
+                    }
+                }
+            }
+            None => {
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() {
+                        break;
+                    }
+                    token.push(c);
+                    chars.next();
+                }
+            }
+        }
+        tokens.push(token);
+    }
+    tokens
+}
+
+fn classify(token: &str) -> TokenType {
+    let inner = token
+        .strip_prefix(['(', '['])
+        .and_then(|t| t.strip_suffix([')', ']']));
+    let is_year = |s: &str| {
+        s.len() == 4
+            && s.parse::<u32>()
+                .is_ok_and(|year| (1900..=2099).contains(&year))
+    };
+    match inner {
+        Some(inner) if is_year(inner) => TokenType::Year,
+        Some(_) => TokenType::Tag,
+        None if is_year(token) => TokenType::Year,
+        None if token.chars().all(|c| c.is_ascii_digit()) => TokenType::Number,
+        None => TokenType::Text,
+    }
+}
+
+/// 识别文件名中各个片段的类型（年份、数字、文字、标记），按 order 重新排列后用空格连接，
+/// 如 "(2021) Movie Name" 按 [Text, Year] 排列为 "Movie Name (2021)"。同类片段保持原来的相对顺序
+pub fn smart_reorder(old_text: &str, rule: SmartReorderRule) -> String {
+    let (name_to_process, extension_to_append) = split_name(old_text, true);
+
+    let tokens: Vec<(TokenType, String)> = tokenize(&name_to_process)
+        .into_iter()
+        .map(|token| (classify(&token), token))
+        .collect();
+    if tokens.is_empty() {
+        return old_text.to_string();
+    }
+
+    let mut ordered: Vec<&str> = Vec::with_capacity(tokens.len());
+    for kind in &rule.order {
+        ordered.extend(
+            tokens
+                .iter()
+                .filter(|(k, _)| k == kind)
+                .map(|(_, t)| t.as_str()),
+        );
+    }
+    ordered.extend(
+        tokens
+            .iter()
+            .filter(|(k, _)| !rule.order.contains(k))
+            .map(|(_, t)| t.as_str()),
+    );
+    format!("{}{}", ordered.join(" "), extension_to_append)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("(2021)", TokenType::Year)]
+    #[case("[1999]", TokenType::Year)]
+    #[case("2021", TokenType::Year)]
+    #[case("1080", TokenType::Number)]
+    #[case("[1080p]", TokenType::Tag)]
+    #[case("(Director's Cut)", TokenType::Tag)]
+    #[case("Movie", TokenType::Text)]
+    fn test_classify(#[case] token: &str, #[case] expected: TokenType) {
+        assert_eq!(classify(token), expected);
+    }
+
+    // 测试样例：把年份放到最后，标记、数字按指定顺序排列
+    #[rstest]
+    #[case("(2021) Movie Name.mkv", &[TokenType::Text, TokenType::Year], "Movie Name (2021).mkv")]
+    #[case(
+        "[1080p] (2019) Show 03 (Director's Cut).mp4",
+        &[TokenType::Text, TokenType::Number, TokenType::Year],
+        "Show 03 (2019) [1080p] (Director's Cut).mp4"
+    )]
+    #[case("Movie Name (2021).mkv", &[TokenType::Year], "(2021) Movie Name.mkv")]
+    #[case("  spaced   out .txt", &[], "spaced out.txt")]
+    fn test_smart_reorder(
+        #[case] input: &str,
+        #[case] order: &[TokenType],
+        #[case] expected: &str,
+    ) {
+        let rule = SmartReorderRule {
+            order: order.to_vec(),
+        };
+
+        let result = smart_reorder(input, rule);
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,88 @@
+use super::common::split_name;
+
+/// 视为等价的分隔符，连续出现时合并为一个空格
+const SEPARATORS: [char; 4] = [' ', '_', '-', '.'];
+
+/// 数字补齐到的位数，足以容纳 u64 的最大值
+const NUMBER_WIDTH: usize = 20;
+
+/// 生成单个文件名的排序键
+fn sort_key(name: &str) -> String {
+    let (stem, extension) = split_name(name, true);
+
+    let mut key = String::new();
+    let mut digits = String::new();
+    let flush = |key: &mut String, digits: &mut String| {
+        if !digits.is_empty() {
+            key.push_str(&"0".repeat(NUMBER_WIDTH.saturating_sub(digits.len())));
+            key.push_str(digits);
+            digits.clear();
+        }
+    };
+    for c in stem.trim_matches(SEPARATORS).chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            continue;
+        }
+        flush(&mut key, &mut digits);
+        if SEPARATORS.contains(&c) {
+            if !key.ends_with(' ') {
+                key.push(' ');
+            }
+        } else {
+            key.extend(c.to_lowercase());
+        }
+    }
+    flush(&mut key, &mut digits);
+    format!("{}{}", key, extension.to_lowercase())
+}
+
+/// 为每个文件名生成只用于排序、不用于重命名的键：转为小写，空格、"_"、"-"、"." 统一为单个空格，
+/// 数字左侧补 '0' 到相同位数，使 "Track_2" 排在 "track 10" 之前。
+/// 只是分隔符、大小写或数字前导零不同的文件名得到相同的键，便于在编号前得到稳定的顺序
+pub fn stable_sort_keys(names: &[String]) -> Vec<String> {
+    names.iter().map(|name| sort_key(name)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    // 测试样例：分隔符、大小写、前导零不同但等价的文件名得到相同的键
+    #[rstest]
+    #[case("Track_01.mp3", "track - 1.MP3")]
+    #[case("My Holiday-Photo.jpg", "my_holiday.photo.jpg")]
+    #[case("__report__2023.pdf", "Report 2023.pdf")]
+    fn test_stable_sort_keys_equivalent(#[case] a: &str, #[case] b: &str) {
+        let keys = stable_sort_keys(&names(&[a, b]));
+        assert_eq!(keys[0], keys[1]);
+    }
+
+    #[rstest]
+    #[case("a.txt", "a.pdf")]
+    #[case("track 1.mp3", "track 11.mp3")]
+    #[case("ab.txt", "a b.txt")]
+    fn test_stable_sort_keys_distinct(#[case] a: &str, #[case] b: &str) {
+        let keys = stable_sort_keys(&names(&[a, b]));
+        assert_ne!(keys[0], keys[1]);
+    }
+
+    #[test]
+    fn test_stable_sort_keys_order() {
+        let inputs = names(&["Track_10.mp3", "track 2.mp3", "TRACK-1.mp3", "intro.mp3"]);
+        let keys = stable_sort_keys(&inputs);
+
+        let mut order: Vec<usize> = (0..inputs.len()).collect();
+        order.sort_by(|&a, &b| keys[a].cmp(&keys[b]));
+        let sorted: Vec<&str> = order.iter().map(|&i| inputs[i].as_str()).collect();
+        assert_eq!(
+            sorted,
+            ["intro.mp3", "TRACK-1.mp3", "track 2.mp3", "Track_10.mp3"]
+        );
+    }
+}
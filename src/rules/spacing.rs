@@ -0,0 +1,85 @@
+use super::common::split_name;
+use regex::{Captures, Regex};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpacingRule {
+    /// 要整理两侧空白的分隔符
+    pub separator: String,
+    /// 分隔符前保留的空格数
+    pub before: usize,
+    /// 分隔符后保留的空格数
+    pub after: usize,
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+}
+
+/// 统一每个分隔符两侧的空格数量，例如 before=1, after=1 时 "a  -b" -> "a - b"。
+/// 位于开头或结尾的分隔符不会在外侧补空格
+pub fn spacing(old_text: &str, rule: SpacingRule) -> String {
+    if rule.separator.is_empty() {
+        return old_text.to_string();
+    }
+    let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
+
+    let pattern = format!(r"\s*{}\s*", regex::escape(&rule.separator));
+    let re = Regex::new(&pattern).expect("转义后的分隔符正则是合法的");
+    let len = name_to_process.len();
+    let result = re.replace_all(&name_to_process, |caps: &Captures| {
+        let whole = caps.get(0).expect("总是有整体匹配");
+        let before = if whole.start() == 0 { 0 } else { rule.before };
+        let after = if whole.end() == len { 0 } else { rule.after };
+        format!(
+            "{}{}{}",
+            " ".repeat(before),
+            rule.separator,
+            " ".repeat(after)
+        )
+    });
+
+    format!("{}{}", result, extension_to_append)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：统一 " - " 两侧的空格
+    #[rstest]
+    #[case("a  -b.mp3", 1, 1, "a - b.mp3")]
+    #[case("a-b.mp3", 1, 1, "a - b.mp3")]
+    #[case("a   -   b - c.mp3", 1, 1, "a - b - c.mp3")]
+    #[case("a - b.mp3", 0, 0, "a-b.mp3")]
+    #[case("- a -.mp3", 1, 1, "- a -.mp3")]
+    #[case("no separator.mp3", 1, 1, "no separator.mp3")]
+    fn test_spacing(
+        #[case] input: &str,
+        #[case] before: usize,
+        #[case] after: usize,
+        #[case] expected: &str,
+    ) {
+        let rule = SpacingRule {
+            separator: "-".to_string(),
+            before,
+            after,
+            ignore_extension: true,
+        };
+
+        let result = spacing(input, rule);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_spacing_multi_char_separator() {
+        let rule = SpacingRule {
+            separator: "&&".to_string(),
+            before: 2,
+            after: 0,
+            ignore_extension: false,
+        };
+
+        let result = spacing("left&&   right", rule);
+        assert_eq!(result, "left  &&right");
+    }
+}
@@ -0,0 +1,132 @@
+use super::common::split_name;
+use serde::{Deserialize, Serialize};
+
+/// 括号的种类
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BracketKind {
+    /// 圆括号 ()
+    Round,
+    /// 方括号 []
+    Square,
+    /// 花括号 {}
+    Curly,
+    /// 尖括号 <>
+    Angle,
+}
+
+impl BracketKind {
+    pub(crate) fn open(self) -> char {
+        match self {
+            BracketKind::Round => '(',
+            BracketKind::Square => '[',
+            BracketKind::Curly => '{',
+            BracketKind::Angle => '<',
+        }
+    }
+
+    pub(crate) fn close(self) -> char {
+        match self {
+            BracketKind::Round => ')',
+            BracketKind::Square => ']',
+            BracketKind::Curly => '}',
+            BracketKind::Angle => '>',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StripBracketsRule {
+    /// 要移除的括号种类
+    pub kinds: Vec<BracketKind>,
+}
+
+/// 移除指定种类的括号及其中的内容，扩展名保持不变。
+/// 只移除配对完整的括号，未闭合或交错的括号按普通字符保留；
+/// 移除后接缝处的连续空白合并为一个
+pub fn strip_brackets(old_text: &str, rule: StripBracketsRule) -> String {
+    let (name_to_process, extension_to_append) = split_name(old_text, true);
+
+    let mut result = String::new();
+    // 尚未闭合的左括号：(种类, 在 result 中的字节位置)
+    let mut open: Vec<(BracketKind, usize)> = Vec::new();
+    let mut just_removed = false;
+    for c in name_to_process.chars() {
+        if let Some(&kind) = rule.kinds.iter().find(|k| k.open() == c) {
+            open.push((kind, result.len()));
+        } else if let Some(&kind) = rule.kinds.iter().find(|k| k.close() == c)
+            && open.last().is_some_and(|&(top, _)| top == kind)
+        {
+            let (_, start) = open.pop().unwrap();
+            result.truncate(start);
+            just_removed = true;
+            continue;
+        }
+
+        if just_removed
+            && c.is_whitespace()
+            && (result.is_empty() || result.ends_with(char::is_whitespace))
+        {
+            continue;
+        }
+        just_removed = false;
+        result.push(c);
+    }
+
+    format!("{}{}", result, extension_to_append)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const ALL: [BracketKind; 4] = [
+        BracketKind::Round,
+        BracketKind::Square,
+        BracketKind::Curly,
+        BracketKind::Angle,
+    ];
+
+    // 测试样例：每种括号单独移除
+    #[rstest]
+    #[case(
+        BracketKind::Round,
+        "Song (Remix) [Explicit].mp3",
+        "Song [Explicit].mp3"
+    )]
+    #[case(
+        BracketKind::Square,
+        "Song (Remix) [Explicit].mp3",
+        "Song (Remix) .mp3"
+    )]
+    #[case(BracketKind::Curly, "Doc {draft} v2.txt", "Doc v2.txt")]
+    #[case(BracketKind::Angle, "Mail <copy>.eml", "Mail .eml")]
+    fn test_strip_brackets_kind(
+        #[case] kind: BracketKind,
+        #[case] input: &str,
+        #[case] expected: &str,
+    ) {
+        let rule = StripBracketsRule { kinds: vec![kind] };
+
+        let result = strip_brackets(input, rule);
+        assert_eq!(result, expected);
+    }
+
+    // 测试样例：多种括号、嵌套与不配对的括号
+    #[rstest]
+    #[case("Song (Remix) [Explicit].mp3", "Song .mp3")]
+    #[case("Album (Deluxe (Remastered)) 2020.flac", "Album 2020.flac")]
+    #[case("Live [Tokyo (Night 2)] set.mp3", "Live set.mp3")]
+    #[case("Broken (open [tag].mp3", "Broken (open .mp3")]
+    #[case("Stray ) close.txt", "Stray ) close.txt")]
+    #[case("Mixed (a [b) c].txt", "Mixed (a .txt")]
+    #[case("[Group] Show 01.mkv", "Show 01.mkv")]
+    fn test_strip_brackets_all(#[case] input: &str, #[case] expected: &str) {
+        let rule = StripBracketsRule {
+            kinds: ALL.to_vec(),
+        };
+
+        let result = strip_brackets(input, rule);
+        assert_eq!(result, expected);
+    }
+}
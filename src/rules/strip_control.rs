@@ -0,0 +1,49 @@
+use super::common::split_name;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StripControlRule {
+    /// 替换控制字符的文本，为空时直接删除
+    pub replacement: String,
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+}
+
+/// 删除或替换文件名中的 ASCII 控制字符（U+0000–U+001F、U+007F），
+/// 如脚本错误留下的 "\r"、"\n"、"\t"，大多数文件系统不允许这些字符
+pub fn strip_control(old_text: &str, rule: StripControlRule) -> String {
+    let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
+
+    let mut result = String::with_capacity(name_to_process.len());
+    for c in name_to_process.chars() {
+        if c.is_ascii_control() {
+            result.push_str(&rule.replacement);
+        } else {
+            result.push(c);
+        }
+    }
+    format!("{}{}", result, extension_to_append)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：嵌入的制表符、换行符等控制字符
+    #[rstest]
+    #[case("report\tfinal\n.txt", "", "reportfinal.txt")]
+    #[case("report\tfinal\n.txt", "_", "report_final_.txt")]
+    #[case("line\r\nbreak.txt", " ", "line  break.txt")]
+    #[case("nul\u{0}del\u{7f}.bin", "", "nuldel.bin")]
+    #[case("tab\u{85}ok.txt", "", "tab\u{85}ok.txt")]
+    fn test_strip_control(#[case] input: &str, #[case] replacement: &str, #[case] expected: &str) {
+        let rule = StripControlRule {
+            replacement: replacement.to_string(),
+            ignore_extension: true,
+        };
+
+        let result = strip_control(input, rule);
+        assert_eq!(result, expected);
+    }
+}
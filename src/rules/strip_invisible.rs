@@ -0,0 +1,74 @@
+use super::common::split_name;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StripInvisibleRule {
+    /// 把不换行空格（U+00A0、U+2007、U+202F）替换为普通空格
+    pub replace_nbsp_with_space: bool,
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+}
+
+/// 零宽字符、BOM 以及方向控制等不可见的格式字符
+fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00AD}'
+            | '\u{061C}'
+            | '\u{180E}'
+            | '\u{200B}'..='\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2060}'..='\u{2064}'
+            | '\u{2066}'..='\u{2069}'
+            | '\u{FEFF}'
+    )
+}
+
+fn is_nbsp(c: char) -> bool {
+    matches!(c, '\u{00A0}' | '\u{2007}' | '\u{202F}')
+}
+
+/// 移除从网页或文档中复制文件名时常带入的零宽字符和 BOM
+pub fn strip_invisible(old_text: &str, rule: StripInvisibleRule) -> String {
+    let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
+
+    let result: String = name_to_process
+        .chars()
+        .filter(|&c| !is_invisible(c))
+        .map(|c| {
+            if rule.replace_nbsp_with_space && is_nbsp(c) {
+                ' '
+            } else {
+                c
+            }
+        })
+        .collect();
+    format!("{}{}", result, extension_to_append)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：移除零宽字符，按需替换不换行空格
+    #[rstest]
+    #[case("re\u{200B}port\u{00A0}final.pdf", true, "report final.pdf")]
+    #[case("re\u{200B}port\u{00A0}final.pdf", false, "report\u{00A0}final.pdf")]
+    #[case("\u{FEFF}notes.txt", true, "notes.txt")]
+    #[case("soft\u{00AD}hyphen\u{2060}.md", false, "softhyphen.md")]
+    #[case("plain name.txt", true, "plain name.txt")]
+    fn test_strip_invisible(
+        #[case] input: &str,
+        #[case] replace_nbsp_with_space: bool,
+        #[case] expected: &str,
+    ) {
+        let rule = StripInvisibleRule {
+            replace_nbsp_with_space,
+            ignore_extension: true,
+        };
+
+        let result = strip_invisible(input, rule);
+        assert_eq!(result, expected);
+    }
+}
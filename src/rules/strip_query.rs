@@ -0,0 +1,46 @@
+use super::common::split_name;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StripQueryRule {
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+}
+
+/// 去掉浏览器保存文件时带上的查询参数或锚点，即从第一个 "?" 或 "#" 开始到末尾的部分，
+/// 如 "image?width=100.jpg" -> "image.jpg"。"?" 或 "#" 位于开头时保持原样，避免得到空文件名
+pub fn strip_query(old_text: &str, rule: StripQueryRule) -> String {
+    let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
+
+    match name_to_process.find(['?', '#']) {
+        Some(index) if index > 0 => {
+            format!("{}{}", &name_to_process[..index], extension_to_append)
+        }
+        _ => old_text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：查询参数、锚点，以及没有可去掉内容的文件名
+    #[rstest]
+    #[case("image?width=100.jpg", true, "image.jpg")]
+    #[case("report.pdf?utm_source=mail&utm_medium=x", false, "report.pdf")]
+    #[case("page#section-2.html", true, "page.html")]
+    #[case("a?b#c.txt", true, "a.txt")]
+    #[case("?query.txt", true, "?query.txt")]
+    #[case("plain.txt", true, "plain.txt")]
+    fn test_strip_query(
+        #[case] input: &str,
+        #[case] ignore_extension: bool,
+        #[case] expected: &str,
+    ) {
+        let rule = StripQueryRule { ignore_extension };
+
+        let result = strip_query(input, rule);
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,99 @@
+use super::common::{InsertPosition, insert_text};
+use super::error::RuleError;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// 要统计的内容
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TextStat {
+    /// 行数，末尾没有换行符的最后一行也计算在内
+    Lines,
+    /// 以空白分隔的单词数
+    Words,
+    /// 字符数（Unicode 标量值）
+    Chars,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TextStatsRule {
+    /// 要统计的内容
+    pub stat: TextStat,
+    /// 插入的位置
+    pub position: InsertPosition,
+    /// 统计值与原文件名之间的分隔符
+    pub separator: String,
+}
+
+/// 统计文本文件的行数、单词数或字符数并插入到文件名中。
+/// 不是 UTF-8 文本的文件保持原文件名
+pub fn text_stats(path: &Path, rule: TextStatsRule) -> Result<String, RuleError> {
+    let old_text = path
+        .file_name()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    let Ok(content) = String::from_utf8(fs::read(path)?) else {
+        return Ok(old_text); // 不是文本文件
+    };
+    let count = match rule.stat {
+        TextStat::Lines => content.lines().count(),
+        TextStat::Words => content.split_whitespace().count(),
+        TextStat::Chars => content.chars().count(),
+    };
+
+    Ok(insert_text(
+        &old_text,
+        &count.to_string(),
+        rule.position,
+        &rule.separator,
+        true,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：三种统计值 + 前/后缀
+    #[rstest]
+    #[case(TextStat::Lines, InsertPosition::Suffix, "notes_3.txt")]
+    #[case(TextStat::Words, InsertPosition::Suffix, "notes_6.txt")]
+    #[case(TextStat::Chars, InsertPosition::Prefix, "22_notes.txt")]
+    fn test_text_stats(
+        #[case] stat: TextStat,
+        #[case] position: InsertPosition,
+        #[case] expected: &str,
+    ) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notes.txt");
+        fs::write(&path, "one two\nthree\nfour 五 六").unwrap();
+
+        let rule = TextStatsRule {
+            stat,
+            position,
+            separator: "_".to_string(),
+        };
+
+        let result = text_stats(&path, rule).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_text_stats_not_text() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("image.png");
+        fs::write(&path, [0x89, b'P', b'N', b'G', 0xff, 0xfe]).unwrap();
+
+        let rule = TextStatsRule {
+            stat: TextStat::Lines,
+            position: InsertPosition::Suffix,
+            separator: "_".to_string(),
+        };
+
+        assert_eq!(text_stats(&path, rule.clone()).unwrap(), "image.png");
+        let result = text_stats(&dir.path().join("missing.txt"), rule);
+        assert!(matches!(result, Err(RuleError::Io(_))));
+    }
+}
@@ -0,0 +1,160 @@
+use super::common::split_name;
+use serde::{Deserialize, Serialize};
+
+/// 首尾要去掉的分隔符
+const EDGE_SEPARATORS: [char; 4] = [' ', '_', '-', '.'];
+
+fn default_true() -> bool {
+    true
+}
+
+/// 一键整理文件名，各个步骤都可以单独关闭，未设置时默认开启
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TidyRule {
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+    /// 去掉首尾空白
+    #[serde(default = "default_true")]
+    pub trim: bool,
+    /// 连续空白合并为一个空格
+    #[serde(default = "default_true")]
+    pub collapse_whitespace: bool,
+    /// 各种破折号统一为 "-"，连续的 "-" 合并为一个
+    #[serde(default = "default_true")]
+    pub normalize_dashes: bool,
+    /// 去掉首尾的空格、"_"、"-"、"."
+    #[serde(default = "default_true")]
+    pub trim_separators: bool,
+    /// 只有一侧有空格的 "-" 两侧都补上空格，如 "a -b" -> "a - b"；两侧都没有空格的 "-" 不变
+    #[serde(default = "default_true")]
+    pub space_around_dash: bool,
+}
+
+impl Default for TidyRule {
+    fn default() -> Self {
+        TidyRule {
+            ignore_extension: true,
+            trim: true,
+            collapse_whitespace: true,
+            normalize_dashes: true,
+            trim_separators: true,
+            space_around_dash: true,
+        }
+    }
+}
+
+fn normalize_dashes(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        let c = match c {
+            '\u{2010}'..='\u{2015}' | '\u{2212}' => '-',
+            c => c,
+        };
+        if !(c == '-' && result.ends_with('-')) {
+            result.push(c);
+        }
+    }
+    result
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !result.ends_with(' ') {
+                result.push(' ');
+            }
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+fn space_around_dash(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut result = String::with_capacity(text.len());
+    for (i, &c) in chars.iter().enumerate() {
+        if c != '-' {
+            result.push(c);
+            continue;
+        }
+        let space_before = i > 0 && chars[i - 1].is_whitespace();
+        let space_after = chars.get(i + 1).is_some_and(|c| c.is_whitespace());
+        if space_before != space_after && i > 0 && i + 1 < chars.len() {
+            if !space_before {
+                result.push(' ');
+            }
+            result.push('-');
+            if !space_after {
+                result.push(' ');
+            }
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// 依次执行：统一破折号、合并空白、修正破折号两侧的空格、去掉首尾分隔符、去掉首尾空白。
+/// 整理后为空时保持原样
+pub fn tidy(old_text: &str, rule: TidyRule) -> String {
+    let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
+
+    let mut name = name_to_process;
+    if rule.normalize_dashes {
+        name = normalize_dashes(&name);
+    }
+    if rule.collapse_whitespace {
+        name = collapse_whitespace(&name);
+    }
+    if rule.space_around_dash {
+        name = space_around_dash(&name);
+    }
+    if rule.trim_separators {
+        name = name.trim_matches(EDGE_SEPARATORS).to_string();
+    }
+    if rule.trim {
+        name = name.trim().to_string();
+    }
+
+    if name.is_empty() {
+        return old_text.to_string();
+    }
+    format!("{}{}", name, extension_to_append)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：故意弄乱的文件名
+    #[rstest]
+    #[case("  --My   report —draft_ .pdf", "My report - draft.pdf")]
+    #[case("a\t\tb -c.txt", "a b - c.txt")]
+    #[case("well-known -- name.txt", "well-known - name.txt")]
+    #[case("__.txt", "__.txt")]
+    #[case("clean name.txt", "clean name.txt")]
+    fn test_tidy(#[case] input: &str, #[case] expected: &str) {
+        let result = tidy(input, TidyRule::default());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_tidy_toggles() {
+        let rule = TidyRule {
+            trim_separators: false,
+            space_around_dash: false,
+            ..Default::default()
+        };
+        assert_eq!(tidy("_a  —b_.txt", rule), "_a -b_.txt");
+
+        let rule: TidyRule = serde_json::from_str(
+            r#"{"ignore_extension": true, "trim": false, "trim_separators": false}"#,
+        )
+        .unwrap();
+        assert!(rule.collapse_whitespace && rule.normalize_dashes && rule.space_around_dash);
+        assert_eq!(tidy(" a  b .txt", rule), " a b .txt");
+    }
+}
@@ -0,0 +1,131 @@
+use super::common::split_name;
+use regex::{Captures, Regex};
+use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
+
+/// 时间片段：小时、可选的分钟、可选的 AM/PM
+static TIME_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\b(\d{1,2})(?::(\d{2}))?(?:\s?([AaPp][Mm]))?\b").expect("时间正则是合法的")
+});
+
+/// 时间的表示方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TimeFormat {
+    /// 12 小时制，如 "3PM"、"3:30PM"
+    Hour12,
+    /// 24 小时制，如 "15:00"。注意 ":" 不能出现在 Windows 文件名中
+    Hour24,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeFormatRule {
+    /// 转换后的表示方式
+    pub to: TimeFormat,
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+}
+
+/// 把一个时间片段转换为目标格式；不是合法时间或已经是目标格式时返回 None
+fn convert(caps: &Captures, to: TimeFormat) -> Option<String> {
+    let hour: u32 = caps[1].parse().ok()?;
+    let minute: u32 = match caps.get(2) {
+        Some(m) => m.as_str().parse().ok()?,
+        None => 0,
+    };
+    if minute >= 60 {
+        return None;
+    }
+
+    match (caps.get(3), to) {
+        (Some(meridiem), TimeFormat::Hour24) => {
+            if !(1..=12).contains(&hour) {
+                return None;
+            }
+            // 12AM 是午夜 00:00，12PM 是中午 12:00
+            let pm = meridiem.as_str().eq_ignore_ascii_case("pm");
+            let hour = match (hour, pm) {
+                (12, false) => 0,
+                (12, true) => 12,
+                (h, false) => h,
+                (h, true) => h + 12,
+            };
+            Some(format!("{:02}:{:02}", hour, minute))
+        }
+        // 24 小时制的片段必须带分钟且小时为两位（如 "09:05"），
+        // 避免把 "Chapter 1:05"、"score 3:15" 这类编号或比分当成时间
+        (None, TimeFormat::Hour12) if caps.get(2).is_some() && caps[1].len() == 2 => {
+            if hour >= 24 {
+                return None;
+            }
+            let meridiem = if hour < 12 { "AM" } else { "PM" };
+            let hour = match hour % 12 {
+                0 => 12,
+                h => h,
+            };
+            if minute == 0 {
+                Some(format!("{}{}", hour, meridiem))
+            } else {
+                Some(format!("{}:{:02}{}", hour, minute, meridiem))
+            }
+        }
+        _ => None,
+    }
+}
+
+/// 识别文件名中的时间（如 "3PM"、"3:30 pm"、"15:00"）并在 12 小时制和 24 小时制之间转换。
+/// 24 小时制的时间必须写成两位小时，"1:05" 这样的片段不会被转换
+pub fn time_format(old_text: &str, rule: TimeFormatRule) -> String {
+    let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
+
+    let converted = TIME_RE.replace_all(&name_to_process, |caps: &Captures| {
+        convert(caps, rule.to).unwrap_or_else(|| caps[0].to_string())
+    });
+    format!("{}{}", converted, extension_to_append)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：12 小时制 -> 24 小时制，含中午和午夜
+    #[rstest]
+    #[case("meeting 3PM.txt", "meeting 15:00.txt")]
+    #[case("call 3:30 pm.txt", "call 15:30.txt")]
+    #[case("12AM backup.log", "00:00 backup.log")]
+    #[case("12PM lunch.txt", "12:00 lunch.txt")]
+    #[case("9am-11am.txt", "09:00-11:00.txt")]
+    #[case("already 15:00.txt", "already 15:00.txt")]
+    #[case("13PM 2023.txt", "13PM 2023.txt")]
+    fn test_time_format_to_24(#[case] input: &str, #[case] expected: &str) {
+        let rule = TimeFormatRule {
+            to: TimeFormat::Hour24,
+            ignore_extension: true,
+        };
+
+        let result = time_format(input, rule);
+        assert_eq!(result, expected);
+    }
+
+    // 测试样例：24 小时制 -> 12 小时制，含中午和午夜
+    #[rstest]
+    #[case("meeting 15:00.txt", "meeting 3PM.txt")]
+    #[case("call 15:30.txt", "call 3:30PM.txt")]
+    #[case("00:00 backup.log", "12AM backup.log")]
+    #[case("12:00 lunch.txt", "12PM lunch.txt")]
+    #[case("09:05.txt", "9:05AM.txt")]
+    #[case("25:00 and 3PM.txt", "25:00 and 3PM.txt")]
+    #[case("track 15.mp3", "track 15.mp3")]
+    #[case("Chapter 1:05.txt", "Chapter 1:05.txt")]
+    #[case("score 3:15 final.txt", "score 3:15 final.txt")]
+    #[case("10:30 standup.txt", "10:30AM standup.txt")]
+    fn test_time_format_to_12(#[case] input: &str, #[case] expected: &str) {
+        let rule = TimeFormatRule {
+            to: TimeFormat::Hour12,
+            ignore_extension: true,
+        };
+
+        let result = time_format(input, rule);
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,93 @@
+use super::common::{build_regex, split_name};
+use super::date_reformat::format_datetime;
+use super::error::RuleError;
+use chrono::{NaiveDateTime, TimeZone};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimezoneShiftRule {
+    /// 用于定位时间戳的正则表达式
+    pub pattern: String,
+    /// 时间戳的格式（chrono strftime 语法）
+    pub input_format: String,
+    /// 时间戳原本所在的时区，IANA 名称，如 "UTC"
+    pub from_tz: String,
+    /// 要转换到的时区，IANA 名称，如 "America/New_York"
+    pub to_tz: String,
+    /// 输出格式（chrono strftime 语法）
+    pub output_format: String,
+}
+
+fn parse_tz(name: &str) -> Result<Tz, RuleError> {
+    name.parse()
+        .map_err(|_| RuleError::InvalidTimezone(name.to_string()))
+}
+
+/// 把文件名中的时间戳从 from_tz 换算到 to_tz 并重新格式化。
+/// 无法按 input_format 解析的匹配，以及在 from_tz 中不存在的时刻（夏令时跳过的时间）保持原样；
+/// 夏令时回拨造成的重复时刻取较早的一个
+pub fn timezone_shift(old_text: &str, rule: TimezoneShiftRule) -> Result<String, RuleError> {
+    let from_tz = parse_tz(&rule.from_tz)?;
+    let to_tz = parse_tz(&rule.to_tz)?;
+    let re = build_regex(&rule.pattern, true)?;
+    let (name_to_process, extension_to_append) = split_name(old_text, true);
+
+    let shifted = re.replace_all(&name_to_process, |caps: &regex::Captures| {
+        let text = &caps[0];
+        NaiveDateTime::parse_from_str(text, &rule.input_format)
+            .ok()
+            .and_then(|naive| from_tz.from_local_datetime(&naive).earliest())
+            .and_then(|datetime| {
+                let local = datetime.with_timezone(&to_tz).naive_local();
+                format_datetime(local, &rule.output_format)
+            })
+            .unwrap_or_else(|| text.to_string())
+    });
+
+    Ok(format!("{}{}", shifted, extension_to_append))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn rule(from_tz: &str, to_tz: &str) -> TimezoneShiftRule {
+        TimezoneShiftRule {
+            pattern: r"\d{8}_\d{6}".to_string(),
+            input_format: "%Y%m%d_%H%M%S".to_string(),
+            from_tz: from_tz.to_string(),
+            to_tz: to_tz.to_string(),
+            output_format: "%Y%m%d_%H%M%S".to_string(),
+        }
+    }
+
+    // 测试样例：UTC 时间戳换算到纽约时间（冬令时 -5，夏令时 -4，可能跨日）
+    #[rstest]
+    #[case("VID_20230115_120000.mp4", "VID_20230115_070000.mp4")]
+    #[case("VID_20230701_120000.mp4", "VID_20230701_080000.mp4")]
+    #[case("VID_20230101_030000.mp4", "VID_20221231_220000.mp4")]
+    #[case("VID_20231399_120000.mp4", "VID_20231399_120000.mp4")]
+    fn test_timezone_shift(#[case] input: &str, #[case] expected: &str) {
+        let result = timezone_shift(input, rule("UTC", "America/New_York")).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_timezone_shift_output_format() {
+        let rule = TimezoneShiftRule {
+            output_format: "%Y-%m-%d %H.%M".to_string(),
+            ..rule("Asia/Shanghai", "UTC")
+        };
+
+        let result = timezone_shift("IMG_20230501_080000.jpg", rule).unwrap();
+        assert_eq!(result, "IMG_2023-05-01 00.00.jpg");
+    }
+
+    #[test]
+    fn test_timezone_shift_invalid_timezone() {
+        let result = timezone_shift("a.txt", rule("UTC", "Mars/Olympus"));
+        assert!(matches!(result, Err(RuleError::InvalidTimezone(name)) if name == "Mars/Olympus"));
+    }
+}
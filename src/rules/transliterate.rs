@@ -0,0 +1,121 @@
+use super::common::split_name;
+use super::error::RuleError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransliterateRule {
+    /// 原文本 -> 转写文本，如 "ж" -> "zh"；键为空的项会被忽略
+    pub map: HashMap<String, String>,
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+}
+
+impl TransliterateRule {
+    /// 生成反向的转写规则，使转写可以还原。
+    /// 多个键对应同一个值，或值为空时无法反转，返回的错误中列出这些值
+    pub fn invert(&self) -> Result<TransliterateRule, RuleError> {
+        let mut inverse: HashMap<String, String> = HashMap::new();
+        let mut conflicts = Vec::new();
+        for (key, value) in &self.map {
+            if key.is_empty() {
+                continue;
+            }
+            if value.is_empty() || inverse.insert(value.clone(), key.clone()).is_some() {
+                conflicts.push(value.clone());
+            }
+        }
+        if !conflicts.is_empty() {
+            conflicts.sort();
+            conflicts.dedup();
+            return Err(RuleError::NotInvertible(conflicts));
+        }
+        Ok(TransliterateRule {
+            map: inverse,
+            ignore_extension: self.ignore_extension,
+        })
+    }
+}
+
+/// 按映射表逐段转写文件名，每个位置优先匹配最长的键
+pub fn transliterate(old_text: &str, rule: TransliterateRule) -> String {
+    let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
+
+    let mut keys: Vec<&String> = rule.map.keys().filter(|k| !k.is_empty()).collect();
+    keys.sort_by(|a, b| b.len().cmp(&a.len()).then(a.cmp(b)));
+
+    let mut result = String::new();
+    let mut rest = name_to_process.as_str();
+    while let Some(c) = rest.chars().next() {
+        match keys.iter().find(|k| rest.starts_with(k.as_str())) {
+            Some(key) => {
+                result.push_str(&rule.map[*key]);
+                rest = &rest[key.len()..];
+            }
+            None => {
+                result.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    format!("{}{}", result, extension_to_append)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn cyrillic() -> TransliterateRule {
+        let map = [
+            ("м", "m"),
+            ("о", "o"),
+            ("с", "s"),
+            ("к", "k"),
+            ("в", "v"),
+            ("а", "a"),
+            ("ж", "zh"),
+            ("у", "u"),
+            ("р", "r"),
+            ("н", "n"),
+            ("л", "l"),
+            ("М", "M"),
+            ("Ж", "Zh"),
+        ];
+        TransliterateRule {
+            map: map
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            ignore_extension: true,
+        }
+    }
+
+    // 测试样例：转写后用反向规则还原
+    #[rstest]
+    #[case("Москва.txt", "Moskva.txt")]
+    #[case("журнал.pdf", "zhurnal.pdf")]
+    #[case("Журнал 2023.pdf", "Zhurnal 2023.pdf")]
+    fn test_transliterate_round_trip(#[case] input: &str, #[case] expected: &str) {
+        let rule = cyrillic();
+        let inverse = rule.invert().unwrap();
+
+        let result = transliterate(input, rule);
+        assert_eq!(result, expected);
+        assert_eq!(transliterate(&result, inverse), input);
+    }
+
+    #[test]
+    fn test_invert_not_bijective() {
+        let mut rule = cyrillic();
+        rule.map.insert("е".to_string(), "e".to_string());
+        rule.map.insert("э".to_string(), "e".to_string());
+        rule.map.insert("ь".to_string(), String::new());
+
+        let result = rule.invert();
+        assert!(matches!(
+            result,
+            Err(RuleError::NotInvertible(conflicts)) if conflicts == vec!["", "e"]
+        ));
+    }
+}
@@ -0,0 +1,108 @@
+use super::common::split_name;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TruncateRule {
+    /// 保留的最大字符数
+    pub max_len: usize,
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TruncateWordRule {
+    /// 保留的最大字符数
+    pub max_len: usize,
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+}
+
+/// 返回前 max_len 个字符的字节位置，文本不足 max_len 个字符时返回 None
+fn cut_index(text: &str, max_len: usize) -> Option<usize> {
+    text.char_indices().nth(max_len).map(|(i, _)| i)
+}
+
+/// 按字符数硬截断，可能把单词截成两半
+pub fn truncate(old_text: &str, rule: TruncateRule) -> String {
+    let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
+
+    match cut_index(&name_to_process, rule.max_len) {
+        Some(end) => format!("{}{}", &name_to_process[..end], extension_to_append),
+        None => old_text.to_string(),
+    }
+}
+
+/// 截断到最多 max_len 个字符，并回退到最后一个空白处，避免截断单词。
+/// 如果第一个单词就超过 max_len，则退回为硬截断
+pub fn truncate_word(old_text: &str, rule: TruncateWordRule) -> String {
+    let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
+
+    let Some(end) = cut_index(&name_to_process, rule.max_len) else {
+        return old_text.to_string();
+    };
+    // 截断处正好是空白时，前面的单词是完整的
+    let next_is_space = name_to_process[end..]
+        .chars()
+        .next()
+        .is_some_and(char::is_whitespace);
+    let head = &name_to_process[..end];
+    let kept = if next_is_space {
+        head
+    } else {
+        match head.rfind(char::is_whitespace) {
+            Some(space) if !head[..space].trim_end().is_empty() => &head[..space],
+            _ => head,
+        }
+    };
+
+    format!("{}{}", kept.trim_end(), extension_to_append)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：硬截断与按单词截断的对比
+    #[rstest]
+    #[case("The quick brown fox.txt", 12, "The quick br.txt", "The quick.txt")]
+    #[case("The quick brown fox.txt", 9, "The quick.txt", "The quick.txt")]
+    #[case("The quick brown fox.txt", 10, "The quick .txt", "The quick.txt")]
+    #[case("Supercalifragilistic.txt", 5, "Super.txt", "Super.txt")]
+    #[case("short.txt", 10, "short.txt", "short.txt")]
+    #[case("中文 文件名 很长.txt", 5, "中文 文件.txt", "中文.txt")]
+    fn test_truncate_compare(
+        #[case] input: &str,
+        #[case] max_len: usize,
+        #[case] hard: &str,
+        #[case] word: &str,
+    ) {
+        let hard_result = truncate(
+            input,
+            TruncateRule {
+                max_len,
+                ignore_extension: true,
+            },
+        );
+        let word_result = truncate_word(
+            input,
+            TruncateWordRule {
+                max_len,
+                ignore_extension: true,
+            },
+        );
+        assert_eq!(hard_result, hard);
+        assert_eq!(word_result, word);
+    }
+
+    #[test]
+    fn test_truncate_not_ignore_extension() {
+        let rule = TruncateRule {
+            max_len: 6,
+            ignore_extension: false,
+        };
+
+        let result = truncate("report.pdf", rule);
+        assert_eq!(result, "report");
+    }
+}
@@ -0,0 +1,163 @@
+use super::common::{render_template, split_name};
+use super::error::RuleError;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// 删除重复的集数标记后，需要一并去掉的分隔符
+const SEPARATORS: [char; 4] = [' ', '.', '_', '-'];
+
+/// 可识别的季/集写法：S01E02、1x02、Season 1 Episode 2
+const EPISODE_PATTERN: &str = r"(?i)S(?P<s1>\d{1,2})[ ._-]?E(?P<e1>\d{1,3})|(?P<s2>\d{1,2})x(?P<e2>\d{2,3})|Season[ ._-]*(?P<s3>\d{1,2})[ ._-]*,?[ ._-]*Episode[ ._-]*(?P<e3>\d{1,3})";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TvCleanupRule {
+    /// 统一后的写法，`{season}`、`{episode}` 为季号和集号，
+    /// `{season:2}` 表示左侧补 '0' 到 2 位；`{{` 和 `}}` 表示字面的花括号
+    pub keep_format: String,
+}
+
+impl Default for TvCleanupRule {
+    fn default() -> Self {
+        TvCleanupRule {
+            keep_format: "S{season:2}E{episode:2}".to_string(),
+        }
+    }
+}
+
+/// 用季号和集号渲染 keep_format
+fn render(format: &str, season: u32, episode: u32) -> Result<String, RuleError> {
+    render_template(format, |token| {
+        let unknown = || RuleError::UnknownToken(token.to_string());
+        let (key, width) = match token.split_once(':') {
+            Some((key, width)) => (key, width.parse::<usize>().map_err(|_| unknown())?),
+            None => (token, 0),
+        };
+        let value = match key {
+            "season" => season,
+            "episode" => episode,
+            _ => return Err(unknown()),
+        };
+        Ok(format!("{:0width$}", value, width = width))
+    })
+}
+
+/// 识别文件名中各种季/集写法（S01E02、1x02、Season 1 Episode 2），
+/// 把第一处改写为 keep_format 的形式，并删除之后表示同一季同一集的重复标记
+/// （连同紧邻的分隔符和包围它的括号）。表示其他集数的标记保持不变；keep_format 中有未知占位符时返回错误
+pub fn tv_cleanup(old_text: &str, rule: TvCleanupRule) -> Result<String, RuleError> {
+    // 先校验格式，使错误不依赖于文件名中是否有集数
+    render(&rule.keep_format, 0, 0)?;
+    let (name_to_process, extension_to_append) = split_name(old_text, true);
+    let re = Regex::new(EPISODE_PATTERN).expect("集数正则表达式有效");
+
+    // 文件名中常用 "_" 和 "." 连接单词，所以这里用字母数字判断边界，而不是正则的 \b
+    let is_word_char = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+    let mut found = Vec::new();
+    let mut pos = 0;
+    while let Some(caps) = re.captures_at(&name_to_process, pos) {
+        let m = caps.get(0).unwrap();
+        let before = name_to_process[..m.start()].chars().next_back();
+        let after = name_to_process[m.end()..].chars().next();
+        if is_word_char(before) || is_word_char(after) {
+            pos = m.start()
+                + name_to_process[m.start()..]
+                    .chars()
+                    .next()
+                    .map_or(1, char::len_utf8);
+            continue;
+        }
+        let number = |a: &str, b: &str, c: &str| {
+            caps.name(a)
+                .or_else(|| caps.name(b))
+                .or_else(|| caps.name(c))
+                .and_then(|n| n.as_str().parse::<u32>().ok())
+        };
+        if let (Some(season), Some(episode)) = (number("s1", "s2", "s3"), number("e1", "e2", "e3"))
+        {
+            found.push((m.range(), season, episode));
+        }
+        pos = m.end();
+    }
+
+    let Some(&(ref first, season, episode)) = found.first() else {
+        return Ok(old_text.to_string());
+    };
+    let canonical = render(&rule.keep_format, season, episode)?;
+
+    let mut result = name_to_process.clone();
+    // 从后往前删除重复标记，前面的下标不受影响
+    for (range, _, _) in found
+        .iter()
+        .skip(1)
+        .rev()
+        .filter(|(_, s, e)| (*s, *e) == (season, episode))
+    {
+        let (mut start, mut end) = (range.start, range.end);
+        let open = result[..start].chars().next_back();
+        let close = result[end..].chars().next();
+        if matches!(
+            (open, close),
+            (Some('('), Some(')')) | (Some('['), Some(']'))
+        ) {
+            start -= 1;
+            end += 1;
+        }
+        let trimmed = result[..start].trim_end_matches(SEPARATORS).len();
+        if trimmed == 0 {
+            end = result.len() - result[end..].trim_start_matches(SEPARATORS).len();
+        }
+        start = trimmed;
+        result.replace_range(start..end, "");
+    }
+    result.replace_range(first.clone(), &canonical);
+
+    Ok(format!("{}{}", result, extension_to_append))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：统一为 S01E02 并删除重复的标记
+    #[rstest]
+    #[case("Show 1x02.mkv", "Show S01E02.mkv")]
+    #[case("Show Season 1 Episode 2.mkv", "Show S01E02.mkv")]
+    #[case("Show.season.1.episode.2.mkv", "Show.S01E02.mkv")]
+    #[case("Show s1e2.mkv", "Show S01E02.mkv")]
+    #[case("Show.S01E02.1x02.720p.mkv", "Show.S01E02.720p.mkv")]
+    #[case("Show - s1e2 (Season 1 Episode 2).mkv", "Show - S01E02.mkv")]
+    #[case("Show S01E02 S01E03.mkv", "Show S01E02 S01E03.mkv")]
+    #[case("Show 1920x1080.mkv", "Show 1920x1080.mkv")]
+    #[case("Shows1e2.mkv", "Shows1e2.mkv")]
+    fn test_tv_cleanup(#[case] input: &str, #[case] expected: &str) {
+        let result = tv_cleanup(input, TvCleanupRule::default()).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_tv_cleanup_custom_format() {
+        let rule = TvCleanupRule {
+            keep_format: "{season}x{episode:2}".to_string(),
+        };
+
+        let result = tv_cleanup("Show S01E02 [Season 1 Episode 2].mkv", rule).unwrap();
+        assert_eq!(result, "Show 1x02.mkv");
+    }
+
+    #[test]
+    fn test_tv_cleanup_unknown_token() {
+        let rule = TvCleanupRule {
+            keep_format: "{show} S{season:2}".to_string(),
+        };
+
+        let result = tv_cleanup("Show.mkv", rule);
+        assert!(matches!(result, Err(RuleError::UnknownToken(t)) if t == "show"));
+
+        let rule = TvCleanupRule {
+            keep_format: "S{season:2}E{episode".to_string(),
+        };
+        let result = tv_cleanup("Show 1x02.mkv", rule);
+        assert!(matches!(result, Err(RuleError::UnterminatedToken(_))));
+    }
+}
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+use unicode_normalization::UnicodeNormalization;
+
+/// Unicode 规范化形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UnicodeForm {
+    /// 标准组合形式，大多数系统使用
+    NFC,
+    /// 标准分解形式，macOS 文件系统使用
+    NFD,
+    /// 兼容组合形式，如 "ﬁ" 变为 "fi"
+    NFKC,
+    /// 兼容分解形式
+    NFKD,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnicodeFormRule {
+    /// 目标规范化形式
+    pub form: UnicodeForm,
+}
+
+/// 把整个文件名转换为指定的 Unicode 规范化形式，
+/// 使来自不同系统、看起来相同的文件名在字节上也相同
+pub fn unicode_form(old_text: &str, rule: UnicodeFormRule) -> String {
+    match rule.form {
+        UnicodeForm::NFC => old_text.nfc().collect(),
+        UnicodeForm::NFD => old_text.nfd().collect(),
+        UnicodeForm::NFKC => old_text.nfkc().collect(),
+        UnicodeForm::NFKD => old_text.nfkd().collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：分解形式的 "é" 与组合形式互相转换
+    #[rstest]
+    #[case("cafe\u{301}.txt", UnicodeForm::NFC, "caf\u{e9}.txt")]
+    #[case("caf\u{e9}.txt", UnicodeForm::NFD, "cafe\u{301}.txt")]
+    #[case("\u{fb01}le.txt", UnicodeForm::NFKC, "file.txt")]
+    #[case("\u{fb01}le.txt", UnicodeForm::NFC, "\u{fb01}le.txt")]
+    #[case("\u{2460}\u{e9}.txt", UnicodeForm::NFKD, "1e\u{301}.txt")]
+    fn test_unicode_form(#[case] input: &str, #[case] form: UnicodeForm, #[case] expected: &str) {
+        let result = unicode_form(input, UnicodeFormRule { form });
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_unicode_form_bytes() {
+        let decomposed = "re\u{301}sume\u{301}.pdf";
+        let composed = "r\u{e9}sum\u{e9}.pdf";
+        assert_ne!(decomposed.as_bytes(), composed.as_bytes());
+
+        let result = unicode_form(
+            decomposed,
+            UnicodeFormRule {
+                form: UnicodeForm::NFC,
+            },
+        );
+        assert_eq!(result.as_bytes(), composed.as_bytes());
+        assert_eq!(result.len(), decomposed.len() - 2);
+    }
+}
@@ -0,0 +1,87 @@
+use super::error::RuleError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// 统一整批文件的扩展名大小写：每种扩展名（不区分大小写）改为批次中最常见的写法，
+/// 如 5 个 ".jpg" 和 1 个 ".JPG" 统一为 ".jpg"。出现次数相同时优先选择小写写法
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UnifyExtensionCaseRule {}
+
+fn extension_span(name: &str) -> Option<(usize, &str)> {
+    let path = Path::new(name);
+    path.file_stem()?;
+    let ext = path.extension()?.to_str()?;
+    Some((name.len() - ext.len(), ext))
+}
+
+pub fn unify_extension_case(
+    names: &[String],
+    _rule: UnifyExtensionCaseRule,
+) -> Result<Vec<String>, RuleError> {
+    // 第一遍：统计每种扩展名各种写法出现的次数
+    let mut counts: HashMap<String, HashMap<&str, usize>> = HashMap::new();
+    for name in names {
+        if let Some((_, ext)) = extension_span(name) {
+            *counts
+                .entry(ext.to_lowercase())
+                .or_default()
+                .entry(ext)
+                .or_insert(0) += 1;
+        }
+    }
+    let preferred: HashMap<String, &str> = counts
+        .into_iter()
+        .map(|(key, spellings)| {
+            let best = spellings
+                .into_iter()
+                .max_by(|(a, count_a), (b, count_b)| {
+                    count_a
+                        .cmp(count_b)
+                        .then_with(|| (*a == key).cmp(&(*b == key)))
+                        .then_with(|| b.cmp(a))
+                })
+                .map(|(spelling, _)| spelling)
+                .unwrap_or_default();
+            (key, best)
+        })
+        .collect();
+
+    // 第二遍：把其他写法改为首选写法
+    Ok(names
+        .iter()
+        .map(|name| match extension_span(name) {
+            Some((start, ext)) => {
+                format!("{}{}", &name[..start], preferred[&ext.to_lowercase()])
+            }
+            None => name.clone(),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    // 测试样例：按批次中最常见的写法统一
+    #[rstest]
+    #[case(
+        &["a.jpg", "b.jpg", "c.JPG", "d.jpg", "e.jpg", "f.jpg"],
+        &["a.jpg", "b.jpg", "c.jpg", "d.jpg", "e.jpg", "f.jpg"]
+    )]
+    #[case(
+        &["a.PNG", "b.PNG", "c.png", "notes.txt", "README"],
+        &["a.PNG", "b.PNG", "c.PNG", "notes.txt", "README"]
+    )]
+    #[case(&["a.JPG", "b.jpg", "c.Jpg", "d.JPG", "e.jpg"], &["a.jpg", "b.jpg", "c.jpg", "d.jpg", "e.jpg"])]
+    #[case(&[".bashrc", "x.tar.GZ", "y.tar.gz"], &[".bashrc", "x.tar.gz", "y.tar.gz"])]
+    fn test_unify_extension_case(#[case] input: &[&str], #[case] expected: &[&str]) {
+        let result = unify_extension_case(&names(input), UnifyExtensionCaseRule {}).unwrap();
+        assert_eq!(result, names(expected));
+    }
+}
@@ -0,0 +1,60 @@
+use super::common::split_name;
+
+/// 参与统计的分隔符
+const SEPARATORS: [char; 3] = [' ', '_', '-'];
+
+/// 统计整批文件名（不含扩展名）中空格、下划线、连字符的出现次数，
+/// 把其余两种分隔符统一替换为出现最多的一种，避免同一文件夹中混用 "a b"、"a_b"、"a-b"。
+/// 出现次数最多的分隔符不唯一或没有分隔符时保持原样。
+/// 注意日期中的连字符（如 "2023-01-05"）同样会被替换
+pub fn unify_separators(results: &mut [String]) {
+    let mut counts = [0usize; SEPARATORS.len()];
+    for name in results.iter() {
+        let (stem, _) = split_name(name, true);
+        for c in stem.chars() {
+            if let Some(index) = SEPARATORS.iter().position(|&s| s == c) {
+                counts[index] += 1;
+            }
+        }
+    }
+
+    let max = counts.iter().copied().max().unwrap_or(0);
+    if max == 0 || counts.iter().filter(|&&count| count == max).count() > 1 {
+        return;
+    }
+    let dominant = SEPARATORS[counts.iter().position(|&count| count == max).unwrap()];
+
+    for name in results.iter_mut() {
+        let (stem, extension) = split_name(name, true);
+        let unified: String = stem
+            .chars()
+            .map(|c| if SEPARATORS.contains(&c) { dominant } else { c })
+            .collect();
+        *name = format!("{}{}", unified, extension);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    // 测试样例：以下划线为主的批次，以及没有明显主导分隔符的批次
+    #[rstest]
+    #[case(
+        &["a_b.txt", "c_d_e.txt", "f g.txt", "h-i.tar.gz"],
+        &["a_b.txt", "c_d_e.txt", "f_g.txt", "h_i.tar.gz"]
+    )]
+    #[case(&["my-file.txt", "my file.txt", "my_file-2.txt"], &["my-file.txt", "my-file.txt", "my-file-2.txt"])]
+    #[case(&["a b.txt", "a_b.txt"], &["a b.txt", "a_b.txt"])]
+    #[case(&["ab.txt", "cd.txt"], &["ab.txt", "cd.txt"])]
+    fn test_unify_separators(#[case] input: &[&str], #[case] expected: &[&str]) {
+        let mut results = names(input);
+        unify_separators(&mut results);
+        assert_eq!(results, names(expected));
+    }
+}
@@ -0,0 +1,134 @@
+use super::common::split_name;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// 为重复的文件名追加序号的样式，序号从 2 开始（字母从 b 开始）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DedupeStyle {
+    /// "name (2).txt"
+    #[default]
+    Parenthesized,
+    /// "name_2.txt"
+    Underscore,
+    /// "name-2.txt"
+    Dash,
+    /// "name_b.txt"，超过 z 后为 "aa"、"ab"……
+    AlphaSuffix {
+        /// 文件名与字母之间的分隔符
+        separator: char,
+        /// 使用大写字母
+        uppercase: bool,
+    },
+}
+
+/// 把序号转换为字母：1 -> a、2 -> b、26 -> z、27 -> aa
+fn letters(mut n: usize) -> String {
+    let mut result = Vec::new();
+    while n > 0 {
+        n -= 1;
+        result.push(b'a' + (n % 26) as u8);
+        n /= 26;
+    }
+    result.iter().rev().map(|&b| b as char).collect()
+}
+
+impl DedupeStyle {
+    fn decorate(self, name: &str, n: usize) -> String {
+        let (stem, extension) = split_name(name, true);
+        match self {
+            DedupeStyle::Parenthesized => format!("{} ({}){}", stem, n, extension),
+            DedupeStyle::Underscore => format!("{}_{}{}", stem, n, extension),
+            DedupeStyle::Dash => format!("{}-{}{}", stem, n, extension),
+            DedupeStyle::AlphaSuffix {
+                separator,
+                uppercase,
+            } => {
+                let suffix = letters(n);
+                let suffix = if uppercase {
+                    suffix.to_uppercase()
+                } else {
+                    suffix
+                };
+                format!("{}{}{}{}", stem, separator, suffix, extension)
+            }
+        }
+    }
+}
+
+/// 保证规则链输出的每个文件名都不相同：第一次出现的名称保持不变，
+/// 之后重复的名称在扩展名前追加序号。序号会跳过批次中已经存在的名称，
+/// 因此 "a.txt"、"a.txt"、"a (2).txt" 中的第二个会变为 "a (3).txt"。
+/// 只处理字符串本身，不涉及文件系统，适合在 `apply_rules` 之后调用
+pub fn enforce_unique(results: &mut [String], style: DedupeStyle) {
+    let mut taken: HashSet<String> = results.iter().cloned().collect();
+    let mut seen: HashSet<String> = HashSet::new();
+    for name in results.iter_mut() {
+        if seen.insert(name.clone()) {
+            continue;
+        }
+        let unique = (2..)
+            .map(|n| style.decorate(name, n))
+            .find(|candidate| !taken.contains(candidate))
+            .unwrap();
+        taken.insert(unique.clone());
+        seen.insert(unique.clone());
+        *name = unique;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    // 测试样例：三个相同的输出，不同的序号样式
+    #[rstest]
+    #[case(DedupeStyle::Parenthesized, &["a.txt", "a (2).txt", "a (3).txt"])]
+    #[case(DedupeStyle::Underscore, &["a.txt", "a_2.txt", "a_3.txt"])]
+    #[case(DedupeStyle::Dash, &["a.txt", "a-2.txt", "a-3.txt"])]
+    #[case(
+        DedupeStyle::AlphaSuffix { separator: '_', uppercase: false },
+        &["a.txt", "a_b.txt", "a_c.txt"]
+    )]
+    #[case(
+        DedupeStyle::AlphaSuffix { separator: '-', uppercase: true },
+        &["a.txt", "a-B.txt", "a-C.txt"]
+    )]
+    fn test_enforce_unique(#[case] style: DedupeStyle, #[case] expected: &[&str]) {
+        let mut results = names(&["a.txt", "a.txt", "a.txt"]);
+        enforce_unique(&mut results, style);
+        assert_eq!(results, names(expected));
+    }
+
+    #[test]
+    fn test_enforce_unique_skips_existing() {
+        let mut results = names(&["a.txt", "b", "a.txt", "a (2).txt", "b"]);
+        enforce_unique(&mut results, DedupeStyle::Parenthesized);
+        assert_eq!(
+            results,
+            names(&["a.txt", "b", "a (3).txt", "a (2).txt", "b (2)"])
+        );
+    }
+
+    #[test]
+    fn test_enforce_unique_alpha_suffix() {
+        let style = DedupeStyle::AlphaSuffix {
+            separator: '_',
+            uppercase: false,
+        };
+
+        let mut results = names(&["a", "a", "a", "a"]);
+        enforce_unique(&mut results, style);
+        assert_eq!(results, names(&["a", "a_b", "a_c", "a_d"]));
+
+        let mut results = vec!["a".to_string(); 28];
+        enforce_unique(&mut results, style);
+        assert_eq!(results[25], "a_z");
+        assert_eq!(results[26], "a_aa");
+        assert_eq!(results[27], "a_ab");
+    }
+}
@@ -0,0 +1,56 @@
+use super::common::{InsertPosition, insert_text, split_name};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VariantSuffixRule {
+    /// 变体标记，如 "_thumb"、"@2x"；作为前缀时如 "thumb_"
+    pub suffix: String,
+    /// 标记插入的位置
+    pub position: InsertPosition,
+}
+
+/// 为图片等文件加上变体标记（如 "photo.jpg" -> "photo@2x.jpg"），插入到扩展名之前。
+/// 文件名已经带有该标记时保持原样，因此重复应用不会叠加
+pub fn variant_suffix(old_text: &str, rule: VariantSuffixRule) -> String {
+    let (name_to_process, _) = split_name(old_text, true);
+
+    let tagged = match rule.position {
+        InsertPosition::Prefix => name_to_process.starts_with(&rule.suffix),
+        InsertPosition::Suffix => name_to_process.ends_with(&rule.suffix),
+    };
+    if tagged {
+        return old_text.to_string();
+    }
+    insert_text(old_text, &rule.suffix, rule.position, "", true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：添加变体标记，重复应用结果不变
+    #[rstest]
+    #[case("photo.jpg", "@2x", InsertPosition::Suffix, "photo@2x.jpg")]
+    #[case("photo@2x.jpg", "@2x", InsertPosition::Suffix, "photo@2x.jpg")]
+    #[case("photo.jpg", "_thumb", InsertPosition::Suffix, "photo_thumb.jpg")]
+    #[case("photo.jpg", "thumb_", InsertPosition::Prefix, "thumb_photo.jpg")]
+    #[case("thumb_photo.jpg", "thumb_", InsertPosition::Prefix, "thumb_photo.jpg")]
+    #[case("icon", "@2x", InsertPosition::Suffix, "icon@2x")]
+    fn test_variant_suffix(
+        #[case] input: &str,
+        #[case] suffix: &str,
+        #[case] position: InsertPosition,
+        #[case] expected: &str,
+    ) {
+        let rule = VariantSuffixRule {
+            suffix: suffix.to_string(),
+            position,
+        };
+
+        let once = variant_suffix(input, rule.clone());
+        let twice = variant_suffix(&once, rule);
+        assert_eq!(once, expected);
+        assert_eq!(twice, expected);
+    }
+}
@@ -0,0 +1,53 @@
+use super::common::split_name_numeric;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VersionPadRule {
+    /// 每个版本号分量补零后的最小宽度
+    pub width: usize,
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+}
+
+/// 只处理形如 1.2.10 的点分版本号，把每个分量补零，使其按字典序也能正确排序。
+/// 全为数字的 "扩展名"（如 "v1.2.10" 中的 "10"）视为版本号的一部分
+pub fn version_pad(old_text: &str, rule: VersionPadRule) -> String {
+    let (name_to_process, extension_to_append) =
+        split_name_numeric(old_text, rule.ignore_extension);
+
+    let re = Regex::new(r"\d+(?:\.\d+)+").expect("版本号正则是合法的");
+    let padded = re.replace_all(&name_to_process, |caps: &regex::Captures| {
+        caps[0]
+            .split('.')
+            .map(|part| format!("{:0>width$}", part, width = rule.width))
+            .collect::<Vec<_>>()
+            .join(".")
+    });
+
+    format!("{}{}", padded, extension_to_append)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：补齐版本号各分量
+    #[rstest]
+    #[case("app v1.2.10", 2, "app v01.02.10")]
+    #[case("app v1.2.10", 3, "app v001.002.010")]
+    #[case("app v1.2.10.zip", 2, "app v01.02.10.zip")]
+    #[case("lib-0.9_and_1.10.tar", 3, "lib-000.009_and_001.010.tar")]
+    #[case("release 2023 final.zip", 2, "release 2023 final.zip")]
+    #[case("app v123.4.zip", 2, "app v123.04.zip")]
+    fn test_version_pad(#[case] input: &str, #[case] width: usize, #[case] expected: &str) {
+        let rule = VersionPadRule {
+            width,
+            ignore_extension: true,
+        };
+
+        let result = version_pad(input, rule);
+        assert_eq!(result, expected);
+    }
+}
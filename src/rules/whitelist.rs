@@ -0,0 +1,84 @@
+use super::common::split_name;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WhitelistRule {
+    /// 允许的字符，支持 "a-z" 这样的区间；开头或结尾的 '-' 表示字符本身
+    pub allowed: String,
+    /// 替换不允许字符的文本，可以为空
+    pub replacement: String,
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+}
+
+/// 把 "a-zA-Z0-9_-" 这样的描述解析为字符集合
+fn parse_allowed(allowed: &str) -> HashSet<char> {
+    let chars: Vec<char> = allowed.chars().collect();
+    let mut set = HashSet::new();
+    let mut i = 0;
+    while i < chars.len() {
+        if i + 2 < chars.len() && chars[i + 1] == '-' {
+            let (from, to) = (chars[i], chars[i + 2]);
+            set.extend(from.min(to)..=from.max(to));
+            i += 3;
+        } else {
+            set.insert(chars[i]);
+            i += 1;
+        }
+    }
+    set
+}
+
+/// 把不在允许集合中的字符逐个替换为 replacement
+pub fn whitelist(old_text: &str, rule: WhitelistRule) -> String {
+    let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
+
+    let allowed = parse_allowed(&rule.allowed);
+    let mut filtered = String::new();
+    for c in name_to_process.chars() {
+        if allowed.contains(&c) {
+            filtered.push(c);
+        } else {
+            filtered.push_str(&rule.replacement);
+        }
+    }
+    format!("{}{}", filtered, extension_to_append)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：只保留 ASCII 字母数字，替换为空或下划线
+    #[rstest]
+    #[case("Café déjà vu!.txt", "a-zA-Z0-9_-", "", "Cafdjvu.txt")]
+    #[case("Café déjà vu!.txt", "a-zA-Z0-9_-", "_", "Caf__d_j__vu_.txt")]
+    #[case("报告-2023_final.pdf", "a-zA-Z0-9_-", "", "-2023_final.pdf")]
+    #[case("a-b c.txt", "-a-c", "", "a-bc.txt")]
+    #[case("z-a.txt", "z-a", "", "za.txt")]
+    fn test_whitelist(
+        #[case] input: &str,
+        #[case] allowed: &str,
+        #[case] replacement: &str,
+        #[case] expected: &str,
+    ) {
+        let rule = WhitelistRule {
+            allowed: allowed.to_string(),
+            replacement: replacement.to_string(),
+            ignore_extension: true,
+        };
+
+        let result = whitelist(input, rule);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_allowed() {
+        let set = parse_allowed("a-c_-");
+        let mut chars: Vec<char> = set.into_iter().collect();
+        chars.sort();
+        assert_eq!(chars, vec!['-', '_', 'a', 'b', 'c']);
+    }
+}
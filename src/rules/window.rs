@@ -0,0 +1,96 @@
+use super::Rule;
+use super::common::split_name;
+use super::error::RuleError;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WindowRule {
+    /// 窗口起始的字符下标（包含）
+    pub start: usize,
+    /// 窗口结束的字符下标（不包含）
+    pub end: usize,
+    /// 应用于窗口内文本的规则
+    pub inner: Box<Rule>,
+}
+
+/// 只对去掉扩展名后的 [start, end) 字符区间应用 inner 规则，其余部分保持不变。
+/// 下标超出文件名长度时截断到末尾，start 不小于 end 时保持原样
+pub fn window(old_text: &str, rule: WindowRule) -> Result<String, RuleError> {
+    let (name_to_process, extension_to_append) = split_name(old_text, true);
+
+    let byte_index = |char_index: usize| {
+        name_to_process
+            .char_indices()
+            .nth(char_index)
+            .map_or(name_to_process.len(), |(i, _)| i)
+    };
+    let start = byte_index(rule.start);
+    let end = byte_index(rule.end);
+    if start >= end {
+        return Ok(old_text.to_string());
+    }
+
+    Ok(format!(
+        "{}{}{}{}",
+        &name_to_process[..start],
+        rule.inner.apply_one(&name_to_process[start..end])?,
+        &name_to_process[end..],
+        extension_to_append
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{CaseKind, CaseRule, RemovePosition, RemoveRule};
+    use rstest::rstest;
+
+    fn remove_rule(text: &str) -> Box<Rule> {
+        Box::new(Rule::Remove(RemoveRule {
+            text: text.to_string(),
+            remove_position: RemovePosition::All,
+            case_sensitive: true,
+            ignore_extension: false,
+            ..Default::default()
+        }))
+    }
+
+    // 测试样例：只在窗口内移除文本，下标越界时截断
+    #[rstest]
+    #[case("a_b_c_d.txt", 0, 4, "abc_d.txt")]
+    #[case("a_b_c_d.txt", 2, 100, "a_bcd.txt")]
+    #[case("a_b_c_d.txt", 3, 3, "a_b_c_d.txt")]
+    #[case("a_b_c_d.txt", 50, 100, "a_b_c_d.txt")]
+    #[case("中_文_名.txt", 1, 3, "中文_名.txt")]
+    fn test_window_remove(
+        #[case] input: &str,
+        #[case] start: usize,
+        #[case] end: usize,
+        #[case] expected: &str,
+    ) {
+        let rule = WindowRule {
+            start,
+            end,
+            inner: remove_rule("_"),
+        };
+
+        let result = window(input, rule).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_window_uppercase_prefix() {
+        let rule = WindowRule {
+            start: 0,
+            end: 3,
+            inner: Box::new(Rule::Case(CaseRule {
+                case: CaseKind::Upper,
+                ignore_extension: false,
+                ..Default::default()
+            })),
+        };
+
+        let result = window("abcdef.txt", rule).unwrap();
+        assert_eq!(result, "ABCdef.txt");
+    }
+}
@@ -0,0 +1,113 @@
+use super::common::split_name;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KeepSide {
+    /// 保留开头的单词
+    First,
+    /// 保留末尾的单词
+    Last,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WordLimitRule {
+    /// 要保留的单词数量，为 0 时不做处理
+    pub count: usize,
+    /// 保留哪一侧的单词
+    pub side: KeepSide,
+    /// 单词分隔符，为空时按空白字符分隔
+    pub separator: String,
+    /// 忽略扩展名
+    pub ignore_extension: bool,
+}
+
+pub fn word_limit(old_text: &str, rule: WordLimitRule) -> String {
+    if rule.count == 0 {
+        return old_text.to_string();
+    }
+
+    let (name_to_process, extension_to_append) = split_name(old_text, rule.ignore_extension);
+
+    let (words, joiner): (Vec<&str>, &str) = if rule.separator.is_empty() {
+        (name_to_process.split_whitespace().collect(), " ")
+    } else {
+        (
+            name_to_process
+                .split(rule.separator.as_str())
+                .filter(|w| !w.is_empty())
+                .collect(),
+            rule.separator.as_str(),
+        )
+    };
+    if words.len() <= rule.count {
+        return old_text.to_string(); // 单词数量不超过 count，全部保留
+    }
+
+    let kept = match rule.side {
+        KeepSide::First => &words[..rule.count],
+        KeepSide::Last => &words[words.len() - rule.count..],
+    };
+    format!("{}{}", kept.join(joiner), extension_to_append)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // 测试样例：按空白分隔保留开头/末尾的单词
+    #[rstest]
+    #[case(
+        "a very long descriptive file name.txt",
+        3,
+        KeepSide::First,
+        "a very long.txt"
+    )]
+    #[case(
+        "a very long descriptive file name.txt",
+        2,
+        KeepSide::Last,
+        "file name.txt"
+    )]
+    #[case("a  very   long.txt", 2, KeepSide::First, "a very.txt")]
+    #[case("short name.txt", 5, KeepSide::First, "short name.txt")]
+    #[case("short name.txt", 0, KeepSide::Last, "short name.txt")]
+    fn test_word_limit_whitespace(
+        #[case] input: &str,
+        #[case] count: usize,
+        #[case] side: KeepSide,
+        #[case] expected: &str,
+    ) {
+        let rule = WordLimitRule {
+            count,
+            side,
+            separator: String::new(),
+            ignore_extension: true,
+        };
+
+        let result = word_limit(input, rule);
+        assert_eq!(result, expected);
+    }
+
+    // 测试样例：自定义分隔符
+    #[rstest]
+    #[case("2023_trip_paris_day1.jpg", 2, KeepSide::First, "2023_trip.jpg")]
+    #[case("2023_trip_paris_day1.jpg", 2, KeepSide::Last, "paris_day1.jpg")]
+    #[case("a__b_c", 2, KeepSide::Last, "b_c")]
+    fn test_word_limit_separator(
+        #[case] input: &str,
+        #[case] count: usize,
+        #[case] side: KeepSide,
+        #[case] expected: &str,
+    ) {
+        let rule = WordLimitRule {
+            count,
+            side,
+            separator: "_".to_string(),
+            ignore_extension: true,
+        };
+
+        let result = word_limit(input, rule);
+        assert_eq!(result, expected);
+    }
+}